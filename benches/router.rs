@@ -0,0 +1,147 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use oxidy::{route, Context, Returns, Server};
+use std::net::{SocketAddr, TcpListener};
+use std::time::Duration;
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::runtime::Runtime;
+
+/*
+ * Route Counts of the Scenarios
+ */
+const ROUTES: [usize; 3] = [10, 100, 1000];
+
+async fn handle(mut c: Context) -> Returns {
+    c.response.body = "OK".to_owned();
+    (c, None)
+}
+/*
+ * Server with `routes` static & `routes` parameterized Routes
+ */
+fn app(routes: usize) -> Server {
+    let mut app = Server::new();
+
+    for i in 0..routes {
+        let method_path: String = format!("get /static/{}", i);
+        app.add(route!(&method_path, handle));
+    }
+
+    for i in 0..routes {
+        let method_path: String = format!("get /dynamic/{}/:id", i);
+        app.add(route!(&method_path, handle));
+    }
+
+    app
+}
+
+fn request_line(path: &str) -> String {
+    format!(
+        "GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        path
+    )
+}
+/*
+ * Dispatch through the Stream-generic Handler on an in-memory Stream
+ */
+async fn request_memory(app: &Server, path: &str) {
+    let (mut client, stream) = duplex(4096);
+
+    client
+        .write_all(request_line(path).as_bytes())
+        .await
+        .unwrap();
+
+    app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+        .await;
+
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+}
+/*
+ * Start Server with Routes on a free Port
+ */
+fn serve(rt: &Runtime, app: Server) -> SocketAddr {
+    let address: SocketAddr = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+
+    rt.spawn(async move { app.run(address).await });
+
+    rt.block_on(async {
+        while TcpStream::connect(address).await.is_err() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    });
+
+    address
+}
+
+async fn request_tcp(address: SocketAddr, path: &str) {
+    let mut stream: TcpStream = TcpStream::connect(address).await.unwrap();
+
+    stream
+        .write_all(request_line(path).as_bytes())
+        .await
+        .unwrap();
+
+    let mut response: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut response).await.unwrap();
+}
+/*
+ * Matching & Dispatch of the last registered Route through `Server::serve`
+ * on an in-memory Stream
+ */
+fn dispatch(c: &mut Criterion) {
+    let rt: Runtime = Runtime::new().unwrap();
+
+    let mut static_group = c.benchmark_group("static routes");
+
+    for routes in ROUTES {
+        let app: Server = app(routes);
+        let path: String = format!("/static/{}", routes - 1);
+
+        static_group.bench_with_input(BenchmarkId::from_parameter(routes), &path, |b, path| {
+            b.to_async(&rt).iter(|| request_memory(&app, path))
+        });
+    }
+
+    static_group.finish();
+
+    let mut dynamic_group = c.benchmark_group("parameterized routes");
+
+    for routes in ROUTES {
+        let app: Server = app(routes);
+        let path: String = format!("/dynamic/{}/42", routes - 1);
+
+        dynamic_group.bench_with_input(BenchmarkId::from_parameter(routes), &path, |b, path| {
+            b.to_async(&rt).iter(|| request_memory(&app, path))
+        });
+    }
+
+    dynamic_group.finish();
+}
+/*
+ * End-to-end over TCP (1000 Routes)
+ */
+fn tcp(c: &mut Criterion) {
+    let rt: Runtime = Runtime::new().unwrap();
+
+    let address: SocketAddr = serve(&rt, app(1000));
+
+    let mut group = c.benchmark_group("tcp");
+
+    group.bench_function("1000 static routes (last)", |b| {
+        b.to_async(&rt).iter(|| request_tcp(address, "/static/999"))
+    });
+
+    group.bench_function("1000 dynamic routes (last)", |b| {
+        b.to_async(&rt)
+            .iter(|| request_tcp(address, "/dynamic/999/42"))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, dispatch, tcp);
+criterion_main!(benches);
@@ -1,10 +1,14 @@
 pub mod macros;
+pub mod middlewares;
+pub mod router;
 pub mod server;
 pub mod structs;
 pub(crate) mod utils;
 
 pub use macros::middleware;
 pub use macros::route;
+pub use router::Router;
 pub use server::Server;
 pub use structs::context::Context;
 pub use structs::definition::Returns;
+pub use structs::method::Method;
@@ -15,9 +15,98 @@
 /// let mut app = Server::new();
 /// app.add(middleware!(mid));
 /// ```
+///
+/// Closures can capture shared state
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use oxidy::{Server, Context, Returns, middleware};
+///
+/// let hits: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(move |mut c: Context| {
+///     let hits: Arc<AtomicUsize> = hits.clone();
+///     async move {
+///         hits.fetch_add(1, Ordering::Relaxed);
+///         c.next = true;
+///         (c, None)
+///     }
+/// }));
+/// ```
+///
+/// Short-circuit: a Middleware that leaves `next` false stops the dispatch.
+/// Following Middlewares & the Route don't run, Tails already collected still
+/// run & the Response written to the Context is sent.
+///
+/// ```
+/// use std::net::SocketAddr;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use oxidy::{Server, Context, Returns, middleware, route, tail};
+/// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+///
+/// async fn timing(mut c: Context) -> Returns {
+///     c.next = true;
+///
+///     tail!{
+///         c,
+///         {
+///             c.response.set_header("X-Tail", "ran").await;
+///             c
+///         }
+///     }
+/// }
+///
+/// async fn auth(mut c: Context) -> Returns {
+///     if c.request.header("authorization").await.is_none() {
+///         c.response.status = 401;
+///         c.response.body = "Unauthorized".to_owned();
+///         return (c, None);
+///     }
+///
+///     c.next = true;
+///     (c, None)
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let called: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+/// let route_called: Arc<AtomicBool> = called.clone();
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(timing));
+/// app.add(middleware!(auth));
+/// app.add(route!("get /", move |mut c: Context| {
+///     route_called.store(true, Ordering::SeqCst);
+///     async move {
+///         c.response.body = "Secret".to_owned();
+///         (c, None)
+///     }
+/// }));
+///
+/// let (mut client, stream) = duplex(1024);
+///
+/// client
+///     .write_all(b"GET / HTTP/1.1\r\nConnection: close\r\n\r\n")
+///     .await
+///     .unwrap();
+///
+/// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+///
+/// let mut response: String = String::new();
+/// client.read_to_string(&mut response).await.unwrap();
+///
+/// assert!(response.starts_with("HTTP/1.1 401 Unauthorized"));
+/// assert!(response.contains("X-Tail: ran"));
+/// assert!(response.ends_with("Unauthorized"));
+/// assert!(!called.load(Ordering::SeqCst));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! middleware {
-    ($func:tt) => {{
+    ($func:expr) => {{
         use std::sync::Arc;
         use $crate::structs::context::Context;
         use $crate::structs::definition::Callback;
@@ -25,7 +114,8 @@ macro_rules! middleware {
          * Function Vec
          */
         let mut funcs: Vec<Arc<Callback>> = Vec::new();
-        funcs.push(Arc::new(Box::new(move |c: Context| Box::pin($func(c)))));
+        let func = $func;
+        funcs.push(Arc::new(Box::new(move |c: Context| Box::pin(func(c)))));
 
         ("*", "*", funcs)
     }};
@@ -13,9 +13,33 @@
 /// let mut app = Server::new();
 /// app.add(route!("get /", route));
 /// ```
+///
+/// Closures can capture shared state
+///
+/// ```
+/// use std::sync::Arc;
+/// use oxidy::{Server, Context, Returns, route};
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let config: Arc<Config> = Arc::new(Config {
+///     name: "oxidy".to_owned(),
+/// });
+///
+/// let mut app = Server::new();
+/// app.add(route!("get /", move |mut c: Context| {
+///     let config: Arc<Config> = config.clone();
+///     async move {
+///         c.response.body = format!("Name: {}", config.name);
+///         (c, None)
+///     }
+/// }));
+/// ```
 #[macro_export]
 macro_rules! route {
-    ($method_path:expr, $($func:tt),*) => {{
+    ($method_path:expr, $($func:expr),*) => {{
         use std::sync::Arc;
         use $crate::structs::definition::Callback;
         use $crate::structs::context::Context;
@@ -51,9 +75,10 @@ macro_rules! route {
          * Function Vec
          */
         let mut funcs: Vec<Arc<Callback>> = Vec::new();
-        $(
-            funcs.push(Arc::new(Box::new(move |c: Context| Box::pin($func(c)))));
-        )*
+        $({
+            let func = $func;
+            funcs.push(Arc::new(Box::new(move |c: Context| Box::pin(func(c)))));
+        })*
 
         (method, path, funcs)
     }};
@@ -0,0 +1,101 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::utils::base64_decode::base64_decode;
+use crate::utils::constant_time_eq::constant_time_eq;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// HTTP Basic Authentication Middleware
+///
+/// Checks the `Authorization: Basic` credentials against the users (name =>
+/// password). Passwords are compared in constant time, also for unknown
+/// users. Requests with missing or wrong credentials respond `401` with a
+/// `WWW-Authenticate` header & short-circuit, authenticated ones continue
+/// with the user name in the `user` state. Only use it over TLS, the
+/// credentials are sent in plain text.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::middlewares::basic_auth::basic_auth;
+/// use std::collections::HashMap;
+///
+/// async fn admin(mut c: Context) -> Returns {
+///     let user: String = c.get_state("user").await.unwrap_or_default();
+///     c.response.body = format!("Hello {}", user);
+///     (c, None)
+/// }
+///
+/// let mut users: HashMap<String, String> = HashMap::new();
+/// users.insert("admin".to_owned(), "secret".to_owned());
+///
+/// let mut app = Server::new();
+/// app.middleware_for("/admin", basic_auth(users));
+/// app.add(route!("get /admin", admin));
+/// ```
+pub fn basic_auth(
+    users: HashMap<String, String>,
+) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+    let users: Arc<HashMap<String, String>> = Arc::new(users);
+
+    move |mut c: Context| {
+        let users: Arc<HashMap<String, String>> = users.clone();
+
+        Box::pin(async move {
+            /*
+             * `Basic base64(user:password)`
+             */
+            let credentials: Option<(String, String)> = c
+                .request
+                .header("authorization")
+                .await
+                .and_then(|x: String| {
+                    let (scheme, token) = x.trim().split_once(' ')?;
+
+                    if !scheme.eq_ignore_ascii_case("basic") {
+                        return None;
+                    }
+
+                    let decoded: String = String::from_utf8(base64_decode(token.trim())?).ok()?;
+                    let (user, password) = decoded.split_once(':')?;
+
+                    Some((user.to_owned(), password.to_owned()))
+                });
+
+            let user: Option<String> = credentials.and_then(|(user, password)| {
+                /*
+                 * Unknown Users compare against an empty Password too
+                 */
+                let (known, expected): (bool, &str) = match users.get(&user) {
+                    Some(x) => (true, x),
+                    None => (false, ""),
+                };
+
+                let valid: bool = constant_time_eq(password.as_bytes(), expected.as_bytes());
+
+                (known && valid).then_some(user)
+            });
+
+            match user {
+                Some(x) => {
+                    c.set_state("user", &x).await;
+                    c.next = true;
+                }
+                None => {
+                    c.response.status = 401;
+                    c.response.body = "Unauthorized".to_owned();
+                    c.response
+                        .set_header(
+                            "WWW-Authenticate",
+                            "Basic realm=\"Restricted\", charset=\"UTF-8\"",
+                        )
+                        .await;
+                }
+            }
+
+            (c, None)
+        })
+    }
+}
@@ -0,0 +1,271 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::structs::payload::Payload;
+use crate::utils::log_error::log_error;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use futures::future::BoxFuture;
+use std::io::{Error, Write};
+use std::sync::Arc;
+
+/// Response Compression Middleware Builder
+///
+/// Requires the `compression` feature. Compresses Response Bodies with `gzip`
+/// or `deflate` when the Client accepts it (`Accept-Encoding`) after the Route
+/// has run. Only textual Content Types (`text/*`, JSON, XML, JavaScript,
+/// SVG, ...) & Responses without one (served as `Server::default_content_type`)
+/// are compressed: Bodies below the threshold, Files, already encoded
+/// Responses & other Content Types (images, audio, video, archives) are
+/// skipped.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::compression::Compression;
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(Compression::new().threshold(512).level(9).middleware()));
+/// ```
+///
+/// Content Types
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, middleware, route};
+/// use oxidy::middlewares::compression::Compression;
+/// use std::net::SocketAddr;
+/// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+///
+/// async fn html(mut c: Context) -> Returns {
+///     c.response.content_type = "text/html; charset=utf-8".to_owned();
+///     c.response.body = "<p>oxidy</p>".repeat(200);
+///     (c, None)
+/// }
+///
+/// async fn unset(mut c: Context) -> Returns {
+///     c.response.body = "oxidy".repeat(500);
+///     (c, None)
+/// }
+///
+/// async fn png(mut c: Context) -> Returns {
+///     c.response.content_type = "image/png".to_owned();
+///     c.response.body = "oxidy".repeat(500);
+///     (c, None)
+/// }
+///
+/// async fn request(app: &Server, path: &str) -> String {
+///     let (mut client, stream) = duplex(64 * 1024);
+///
+///     client
+///         .write_all(format!("GET {} HTTP/1.1\r\nAccept-Encoding: gzip\r\n\r\n", path).as_bytes())
+///         .await
+///         .unwrap();
+///     client.shutdown().await.unwrap();
+///
+///     app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+///
+///     let mut response: Vec<u8> = Vec::new();
+///     client.read_to_end(&mut response).await.unwrap();
+///     String::from_utf8_lossy(&response).to_string()
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut app = Server::new();
+/// app.add(middleware!(Compression::new().middleware()));
+/// app.add(route!("get /html", html));
+/// app.add(route!("get /unset", unset));
+/// app.add(route!("get /png", png));
+///
+/// assert!(request(&app, "/html").await.contains("Content-Encoding: gzip"));
+/// assert!(request(&app, "/unset").await.contains("Content-Encoding: gzip"));
+/// assert!(!request(&app, "/png").await.contains("Content-Encoding"));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Compression {
+    threshold: usize,
+    level: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    Deflate,
+}
+
+impl Default for Compression {
+    fn default() -> Compression {
+        Compression {
+            threshold: 1024,
+            level: 6,
+        }
+    }
+}
+
+impl Compression {
+    /// New Compression Builder (threshold 1024 bytes, level 6)
+    pub fn new() -> Compression {
+        Default::default()
+    }
+    /// Minimum Body Size in Bytes to compress
+    pub fn threshold(mut self, bytes: usize) -> Compression {
+        self.threshold = bytes;
+        self
+    }
+    /// Compression Level from 0 (none) to 9 (best)
+    pub fn level(mut self, level: u32) -> Compression {
+        self.level = level.min(9);
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let compression: Arc<Compression> = Arc::new(self);
+
+        move |c: Context| {
+            let compression: Arc<Compression> = compression.clone();
+            Box::pin(async move { compression.handle(c).await })
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut c: Context) -> Returns {
+        c.next = true;
+
+        let accept_encoding: String = c
+            .request
+            .header("accept-encoding")
+            .await
+            .unwrap_or_default();
+
+        let encoding: Encoding = match accepted(&accept_encoding) {
+            Some(x) => x,
+            None => return (c, None),
+        };
+
+        (
+            c,
+            Some(Box::new(move |c: Context| {
+                let compression: Arc<Compression> = self.clone();
+                Box::pin(async move { compression.compress(c, encoding).await })
+            })),
+        )
+    }
+    /*
+     * Compress Response Body after the Route
+     */
+    async fn compress(&self, mut c: Context, encoding: Encoding) -> Context {
+        let content_type: String = c
+            .response
+            .get_header("content-type")
+            .await
+            .unwrap_or_else(|| c.response.content_type.clone());
+
+        if c.response.payload.is_some()
+            || c.response.body.len() < self.threshold
+            || matches!(c.response.status, 100..=199 | 204 | 304)
+            || c.response.get_header("content-encoding").await.is_some()
+            || !compressible(&content_type)
+        {
+            return c;
+        }
+
+        let compressed: Result<Vec<u8>, Error> = match encoding {
+            Encoding::Gzip => {
+                let mut encoder: GzEncoder<Vec<u8>> =
+                    GzEncoder::new(Vec::new(), flate2::Compression::new(self.level));
+                encoder
+                    .write_all(c.response.body.as_bytes())
+                    .and_then(|_| encoder.finish())
+            }
+            Encoding::Deflate => {
+                let mut encoder: ZlibEncoder<Vec<u8>> =
+                    ZlibEncoder::new(Vec::new(), flate2::Compression::new(self.level));
+                encoder
+                    .write_all(c.response.body.as_bytes())
+                    .and_then(|_| encoder.finish())
+            }
+        };
+
+        match compressed {
+            Ok(x) => {
+                c.response.body = String::new();
+                c.response.payload = Some(Payload::Bytes(x));
+                c.response
+                    .set_header(
+                        "Content-Encoding",
+                        match encoding {
+                            Encoding::Gzip => "gzip",
+                            Encoding::Deflate => "deflate",
+                        },
+                    )
+                    .await;
+                c.response.append_header("Vary", "Accept-Encoding").await;
+            }
+            Err(e) => log_error(&format!("Fail to Compress Response: {}", e)),
+        }
+
+        c
+    }
+}
+/*
+ * Preferred accepted Encoding (gzip over deflate), `q=0` excluded
+ */
+fn accepted(accept_encoding: &str) -> Option<Encoding> {
+    let encodings: Vec<String> = accept_encoding
+        .split(',')
+        .filter_map(|x: &str| {
+            let mut parts = x.split(';');
+            let name: String = parts.next()?.trim().to_lowercase();
+
+            let rejected: bool = parts.any(|p: &str| {
+                let p: String = p.trim().replace(' ', "");
+                p.starts_with("q=") && p[2..].parse::<f32>().map(|q| q == 0.0).unwrap_or(false)
+            });
+
+            if rejected {
+                None
+            } else {
+                Some(name)
+            }
+        })
+        .collect();
+
+    if encodings.iter().any(|x: &String| x == "gzip" || x == "*") {
+        return Some(Encoding::Gzip);
+    }
+
+    if encodings.iter().any(|x: &String| x == "deflate") {
+        return Some(Encoding::Deflate);
+    }
+
+    None
+}
+/*
+ * Only known textual Content Types are compressed
+ * Unknown ones may already be compressed (images, archives, ...)
+ */
+fn compressible(content_type: &str) -> bool {
+    let content_type: String = content_type.to_lowercase();
+    let media_type: &str = content_type.split(';').next().unwrap_or_default().trim();
+    /*
+     * Unset Content Type gets the (textual) Default Content Type
+     */
+    if media_type.is_empty()
+        || media_type.starts_with("text/")
+        || media_type.ends_with("+json")
+        || media_type.ends_with("+xml")
+    {
+        return true;
+    }
+
+    [
+        "application/json",
+        "application/xml",
+        "application/javascript",
+        "application/ecmascript",
+        "application/x-javascript",
+        "application/x-www-form-urlencoded",
+        "application/wasm",
+        "image/svg+xml",
+    ]
+    .contains(&media_type)
+}
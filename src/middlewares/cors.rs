@@ -0,0 +1,232 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::structs::method::Method;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/// CORS Middleware Builder
+///
+/// Adds `Access-Control-*` headers to Responses of allowed Origins & answers
+/// preflight Requests (`OPTIONS` with `Access-Control-Request-Method`) with
+/// `204`. Requests without `Origin` or from other Origins pass unchanged.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Method, middleware};
+/// use oxidy::middlewares::cors::Cors;
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(Cors::new()
+///     .allow_origin("https://example.com")
+///     .allow_methods(&[Method::Get, Method::Post])
+///     .allow_headers(&["Content-Type", "Authorization"])
+///     .allow_credentials(true)
+///     .max_age(3600)
+///     .middleware()));
+/// ```
+///
+/// Preflight Request
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::cors::Cors;
+/// use std::net::SocketAddr;
+/// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut app = Server::new();
+/// app.add(middleware!(Cors::new().max_age(600).middleware()));
+///
+/// let (mut client, stream) = duplex(1024);
+///
+/// client
+///     .write_all(
+///         b"OPTIONS /users HTTP/1.1\r\nOrigin: https://example.com\r\n\
+///           Access-Control-Request-Method: PUT\r\nConnection: close\r\n\r\n",
+///     )
+///     .await
+///     .unwrap();
+///
+/// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+///
+/// let mut response: String = String::new();
+/// client.read_to_string(&mut response).await.unwrap();
+///
+/// assert!(response.starts_with("HTTP/1.1 204 No Content"));
+/// assert!(response.contains("Access-Control-Allow-Origin: *"));
+/// assert!(response.contains("Access-Control-Max-Age: 600"));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cors {
+    origins: Option<Vec<String>>,
+    methods: Vec<Method>,
+    headers: Vec<String>,
+    expose_headers: Vec<String>,
+    credentials: bool,
+    max_age: Option<u64>,
+}
+
+impl Default for Cors {
+    fn default() -> Cors {
+        Cors {
+            origins: None,
+            methods: vec![
+                Method::Get,
+                Method::Head,
+                Method::Post,
+                Method::Put,
+                Method::Patch,
+                Method::Delete,
+            ],
+            headers: Vec::new(),
+            expose_headers: Vec::new(),
+            credentials: false,
+            max_age: None,
+        }
+    }
+}
+
+impl Cors {
+    /// New CORS Builder allowing any Origin
+    pub fn new() -> Cors {
+        Default::default()
+    }
+    /// Allow an exact Origin, e.g. `https://example.com`
+    ///
+    /// Once called only the listed Origins are allowed.
+    pub fn allow_origin(mut self, origin: &str) -> Cors {
+        self.origins
+            .get_or_insert_with(Vec::new)
+            .push(origin.trim_end_matches('/').to_owned());
+        self
+    }
+    /// Allow any Origin (`*`), the Default
+    pub fn allow_any_origin(mut self) -> Cors {
+        self.origins = None;
+        self
+    }
+    /// Set allowed Methods of preflight Requests
+    pub fn allow_methods(mut self, methods: &[Method]) -> Cors {
+        self.methods = methods.to_vec();
+        self
+    }
+    /// Set allowed Request Headers of preflight Requests
+    ///
+    /// Without any, the requested headers are allowed.
+    pub fn allow_headers(mut self, headers: &[&str]) -> Cors {
+        self.headers = headers.iter().map(|x: &&str| x.to_string()).collect();
+        self
+    }
+    /// Set Response Headers readable by the Browser
+    pub fn expose_headers(mut self, headers: &[&str]) -> Cors {
+        self.expose_headers = headers.iter().map(|x: &&str| x.to_string()).collect();
+        self
+    }
+    /// Allow Credentials (Cookies, Authorization)
+    ///
+    /// The Origin is reflected instead of `*`.
+    pub fn allow_credentials(mut self, enable: bool) -> Cors {
+        self.credentials = enable;
+        self
+    }
+    /// Cache preflight Responses for seconds
+    pub fn max_age(mut self, seconds: u64) -> Cors {
+        self.max_age = Some(seconds);
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let cors: Arc<Cors> = Arc::new(self);
+
+        move |c: Context| {
+            let cors: Arc<Cors> = cors.clone();
+            Box::pin(async move { cors.handle(c).await })
+        }
+    }
+
+    async fn handle(&self, mut c: Context) -> Returns {
+        c.next = true;
+
+        let origin: String = match c.request.header("origin").await {
+            Some(x) => x,
+            None => return (c, None),
+        };
+        /*
+         * Origin not allowed passes without CORS Headers
+         */
+        let allow_origin: String = match &self.origins {
+            None if !self.credentials => "*".to_owned(),
+            None => origin.clone(),
+            Some(x) if x.contains(&origin.trim_end_matches('/').to_owned()) => origin.clone(),
+            Some(_) => return (c, None),
+        };
+
+        c.response
+            .set_header("Access-Control-Allow-Origin", &allow_origin)
+            .await;
+
+        if allow_origin != "*" {
+            c.response.append_header("Vary", "Origin").await;
+        }
+
+        if self.credentials {
+            c.response
+                .set_header("Access-Control-Allow-Credentials", "true")
+                .await;
+        }
+        /*
+         * Preflight
+         */
+        let request_method: Option<String> =
+            c.request.header("access-control-request-method").await;
+
+        if c.request.method.to_uppercase() == "OPTIONS" && request_method.is_some() {
+            let methods: Vec<&str> = self.methods.iter().map(|x: &Method| x.as_str()).collect();
+
+            let headers: String = if self.headers.is_empty() {
+                c.request
+                    .header("access-control-request-headers")
+                    .await
+                    .unwrap_or_default()
+            } else {
+                self.headers.join(", ")
+            };
+
+            c.response
+                .set_header("Access-Control-Allow-Methods", &methods.join(", "))
+                .await;
+
+            if !headers.is_empty() {
+                c.response
+                    .set_header("Access-Control-Allow-Headers", &headers)
+                    .await;
+            }
+
+            if let Some(x) = self.max_age {
+                c.response
+                    .set_header("Access-Control-Max-Age", &x.to_string())
+                    .await;
+            }
+
+            c.response.status = 204;
+            c.response.body = String::new();
+            c.next = false;
+
+            return (c, None);
+        }
+
+        if !self.expose_headers.is_empty() {
+            c.response
+                .set_header(
+                    "Access-Control-Expose-Headers",
+                    &self.expose_headers.join(", "),
+                )
+                .await;
+        }
+
+        (c, None)
+    }
+}
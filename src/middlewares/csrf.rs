@@ -0,0 +1,140 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::structs::method::Method;
+use crate::structs::session::Session;
+use crate::utils::constant_time_eq::constant_time_eq;
+use crate::utils::log_error::log_error;
+use crate::utils::random_token::random_token;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/*
+ * Token of the Session, read with `Context::csrf_token`
+ */
+pub(crate) struct CsrfToken(pub(crate) String);
+
+/// CSRF Protection Middleware Builder
+///
+/// Keeps a random Token in the Session (register `Sessions` before it, else
+/// every Request responds `500` & an Error is logged) &
+/// exposes it with `Context::csrf_token` to embed in Forms or send from
+/// Scripts. Requests other than `GET`, `HEAD`, `OPTIONS` & `TRACE` must send
+/// the Token in the header (Default: `X-CSRF-Token`) or the Form Field
+/// (Default: `_csrf`), else they respond `403` & short-circuit. Tokens are
+/// compared in constant time.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route, middleware};
+/// use oxidy::middlewares::csrf::Csrf;
+/// use oxidy::middlewares::session::Sessions;
+///
+/// async fn form(mut c: Context) -> Returns {
+///     let token: String = c.csrf_token().await.unwrap_or_default();
+///     c.response.body = format!(
+///         "<form method=\"post\"><input type=\"hidden\" name=\"_csrf\" value=\"{}\"></form>",
+///         token
+///     );
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(Sessions::new().middleware()));
+/// app.add(middleware!(Csrf::new().header_name("X-XSRF-Token").middleware()));
+/// app.add(route!("get /form", form));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Csrf {
+    header_name: String,
+    field_name: String,
+}
+
+impl Default for Csrf {
+    fn default() -> Csrf {
+        Csrf {
+            header_name: "X-CSRF-Token".to_owned(),
+            field_name: "_csrf".to_owned(),
+        }
+    }
+}
+
+impl Csrf {
+    /// New CSRF Builder (header `X-CSRF-Token`, Form Field `_csrf`)
+    pub fn new() -> Csrf {
+        Default::default()
+    }
+    /// Set the Request Header carrying the Token
+    pub fn header_name(mut self, name: &str) -> Csrf {
+        self.header_name = name.to_owned();
+        self
+    }
+    /// Set the Form Field carrying the Token
+    pub fn field_name(mut self, name: &str) -> Csrf {
+        self.field_name = name.to_owned();
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let csrf: Arc<Csrf> = Arc::new(self);
+
+        move |c: Context| {
+            let csrf: Arc<Csrf> = csrf.clone();
+            Box::pin(async move { csrf.handle(c).await })
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut c: Context) -> Returns {
+        /*
+         * The Token is kept in the Session, without it every Request fails
+         */
+        let session: Session = match c.session().await {
+            Some(x) => x,
+            None => {
+                log_error("CSRF Middleware requires the Session Middleware before it");
+
+                c.response.status = 500;
+                c.response.body = "Internal Server Error".to_owned();
+                return (c, None);
+            }
+        };
+        /*
+         * Token of the Session, created on first use
+         */
+        let token: String = match session.get("_csrf").await {
+            Some(x) => x,
+            None => {
+                let x: String = random_token(32);
+                session.set("_csrf", &x).await;
+                x
+            }
+        };
+
+        c.set_ext(CsrfToken(token.clone())).await;
+        /*
+         * Safe Methods pass
+         */
+        if matches!(
+            c.method().await,
+            Method::Get | Method::Head | Method::Options | Method::Trace
+        ) {
+            c.next = true;
+            return (c, None);
+        }
+
+        let submitted: Option<String> = match c.request.header(&self.header_name).await {
+            Some(x) => Some(x),
+            None => c.request.form_field(&self.field_name).await.ok().flatten(),
+        };
+
+        match submitted {
+            Some(x) if constant_time_eq(x.trim().as_bytes(), token.as_bytes()) => c.next = true,
+            _ => {
+                c.response.status = 403;
+                c.response.body = "Forbidden".to_owned();
+            }
+        }
+
+        (c, None)
+    }
+}
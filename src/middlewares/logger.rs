@@ -0,0 +1,54 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::utils::log_info::log_info;
+use std::time::Instant;
+
+/// Request Logger Middleware
+///
+/// Logs Client Address, Method, URL, HTTP Version, Status, Body Size &
+/// Response Time of every Request after it has been handled, e.g.
+/// `[Log] 127.0.0.1:50312 "GET /users?page=2 HTTP/1.1" 200 512 1.2ms`.
+/// Register it first to time all other Middlewares & Routes. With the `log`
+/// Feature the line is logged at `info` level instead of printed.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::logger::logger;
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(logger));
+/// ```
+pub async fn logger(mut c: Context) -> Returns {
+    let start: Instant = Instant::now();
+
+    c.next = true;
+
+    (
+        c,
+        Some(Box::new(move |c: Context| {
+            Box::pin(async move {
+                let message: String = format!(
+                    "{} \"{} {} HTTP/{:.1}\" {} {} {:.1?}",
+                    c.request.address,
+                    c.request.method.to_uppercase(),
+                    c.request.url,
+                    c.request.http_version,
+                    c.response.status,
+                    c.response.body.len(),
+                    start.elapsed(),
+                );
+                /*
+                 * Prefixed when printed without the `log` Feature
+                 */
+                if cfg!(feature = "log") {
+                    log_info(&message);
+                } else {
+                    log_info(&format!("[Log] {}", message));
+                }
+                c
+            })
+        })),
+    )
+}
@@ -0,0 +1,241 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::structs::pool::Pool;
+use futures::future::BoxFuture;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+/*
+ * Request Duration Histogram Buckets in Seconds (Prometheus Defaults)
+ */
+const BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Prometheus Metrics Registry
+///
+/// Requires the `metrics` feature. The Middleware counts Requests by Method,
+/// Route & Status & observes their Duration, the Handler exposes them in the
+/// Prometheus Text Format together with the Worker Pool. The Route label is
+/// the matched Route Pattern (see `Context::matched_route`) or `unmatched`,
+/// keeping the number of series bounded. Clones share the same Registry.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, route, middleware};
+/// use oxidy::middlewares::metrics::Metrics;
+///
+/// let mut app = Server::new();
+/// let metrics: Metrics = Metrics::new().pool(app.pool());
+///
+/// /* Register first to time all other Middlewares & Routes */
+/// app.add(middleware!(metrics.middleware()));
+/// app.add(route!("get /metrics", metrics.handler()));
+/// ```
+#[derive(Clone, Default)]
+pub struct Metrics {
+    registry: Arc<Mutex<Registry>>,
+    pool: Option<Pool>,
+}
+
+#[derive(Default)]
+struct Registry {
+    /*
+     * (Method, Route, Status) => Count
+     */
+    requests: BTreeMap<(String, String, usize), u64>,
+    /*
+     * (Method, Route) => Histogram
+     */
+    durations: BTreeMap<(String, String), Histogram>,
+}
+
+#[derive(Default)]
+struct Histogram {
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Metrics {
+    /// New Metrics Registry
+    pub fn new() -> Metrics {
+        Default::default()
+    }
+    /// Expose Worker Pool Size, Active & Queued Requests
+    pub fn pool(mut self, pool: Pool) -> Metrics {
+        self.pool = Some(pool);
+        self
+    }
+    /// Build the Middleware Function recording every Request
+    pub fn middleware(&self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let metrics: Metrics = self.clone();
+
+        move |c: Context| {
+            let metrics: Metrics = metrics.clone();
+            Box::pin(async move { metrics.handle(c).await })
+        }
+    }
+    /// Build the Route Function responding the Metrics in Text Format
+    pub fn handler(&self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let metrics: Metrics = self.clone();
+
+        move |mut c: Context| {
+            let metrics: Metrics = metrics.clone();
+
+            Box::pin(async move {
+                c.response.body = metrics.render();
+                c.response.content_type = "text/plain; version=0.0.4; charset=utf-8".to_owned();
+                (c, None)
+            })
+        }
+    }
+    /// Render the Metrics in Prometheus Text Format
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::middlewares::metrics::Metrics;
+    ///
+    /// let metrics: Metrics = Metrics::new();
+    /// assert!(metrics.render().contains("# TYPE oxidy_requests_total counter"));
+    /// ```
+    pub fn render(&self) -> String {
+        let registry: MutexGuard<Registry> = self.registry();
+        let mut out: String = String::new();
+
+        out.push_str("# HELP oxidy_requests_total Total number of HTTP Requests.\n");
+        out.push_str("# TYPE oxidy_requests_total counter\n");
+
+        registry
+            .requests
+            .iter()
+            .for_each(|((method, route, status), count)| {
+                out.push_str(&format!(
+                    "oxidy_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}\n",
+                    escape(method),
+                    escape(route),
+                    status,
+                    count
+                ));
+            });
+
+        out.push_str("# HELP oxidy_request_duration_seconds HTTP Request Duration in Seconds.\n");
+        out.push_str("# TYPE oxidy_request_duration_seconds histogram\n");
+
+        registry
+            .durations
+            .iter()
+            .for_each(|((method, route), histogram)| {
+                let labels: String =
+                    format!("method=\"{}\",route=\"{}\"", escape(method), escape(route));
+
+                BUCKETS.iter().enumerate().for_each(|(i, le)| {
+                    out.push_str(&format!(
+                        "oxidy_request_duration_seconds_bucket{{{},le=\"{}\"}} {}\n",
+                        labels, le, histogram.buckets[i]
+                    ));
+                });
+
+                out.push_str(&format!(
+                    "oxidy_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}\n",
+                    labels, histogram.count
+                ));
+                out.push_str(&format!(
+                    "oxidy_request_duration_seconds_sum{{{}}} {}\n",
+                    labels, histogram.sum
+                ));
+                out.push_str(&format!(
+                    "oxidy_request_duration_seconds_count{{{}}} {}\n",
+                    labels, histogram.count
+                ));
+            });
+        /*
+         * Worker Pool Gauges
+         */
+        if let Some(pool) = &self.pool {
+            [
+                ("oxidy_pool_size", "Number of Workers.", pool.size()),
+                (
+                    "oxidy_pool_active",
+                    "Requests running on a Worker.",
+                    pool.active(),
+                ),
+                (
+                    "oxidy_pool_queued",
+                    "Requests waiting for a Worker.",
+                    pool.queued(),
+                ),
+            ]
+            .iter()
+            .for_each(|(name, help, value)| {
+                out.push_str(&format!(
+                    "# HELP {0} {1}\n# TYPE {0} gauge\n{0} {2}\n",
+                    name, help, value
+                ));
+            });
+        }
+
+        out
+    }
+    /*
+     * Time the Request & record it in a Tail after the Route
+     */
+    async fn handle(self, mut c: Context) -> Returns {
+        let start: Instant = Instant::now();
+
+        c.next = true;
+
+        (
+            c,
+            Some(Box::new(move |c: Context| {
+                let metrics: Metrics = self.clone();
+                Box::pin(async move {
+                    metrics.observe(&c, start).await;
+                    c
+                })
+            })),
+        )
+    }
+    /*
+     * Record a handled Request
+     */
+    async fn observe(&self, c: &Context, start: Instant) {
+        let method: String = c.request.method.to_uppercase();
+        let route: String = c.matched_route().await.unwrap_or("unmatched").to_owned();
+        let seconds: f64 = start.elapsed().as_secs_f64();
+
+        let mut registry: MutexGuard<Registry> = self.registry();
+
+        *registry
+            .requests
+            .entry((method.clone(), route.clone(), c.response.status))
+            .or_default() += 1;
+
+        let histogram: &mut Histogram = registry.durations.entry((method, route)).or_default();
+
+        BUCKETS.iter().enumerate().for_each(|(i, le)| {
+            if seconds <= *le {
+                histogram.buckets[i] += 1;
+            }
+        });
+
+        histogram.sum += seconds;
+        histogram.count += 1;
+    }
+
+    fn registry(&self) -> MutexGuard<'_, Registry> {
+        self.registry.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+/*
+ * Escape Label Value (Backslash, Double Quote & Line Feed)
+ */
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
@@ -0,0 +1,12 @@
+pub mod basic_auth;
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod cors;
+pub mod csrf;
+pub mod logger;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod rate_limit;
+pub mod request_id;
+pub mod security_headers;
+pub mod session;
@@ -0,0 +1,110 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/*
+ * Token Bucket of a Client IP
+ */
+struct Bucket {
+    tokens: f64,
+    last: Instant,
+}
+
+struct Buckets {
+    map: HashMap<IpAddr, Bucket>,
+    sweep: Instant,
+}
+
+/// Rate Limit Middleware (Token Bucket per Client IP)
+///
+/// Every Client IP may send `requests` per duration, with bursts up to
+/// `requests`. Exceeding Requests respond `429 Too Many Requests` with a
+/// `Retry-After` header. Idle Buckets are evicted after the duration. The IP
+/// is the socket peer address, not `X-Forwarded-For`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::rate_limit::rate_limit;
+/// use std::time::Duration;
+///
+/// /* 100 Requests per Minute */
+/// let mut app = Server::new();
+/// app.add(middleware!(rate_limit(100, Duration::from_secs(60))));
+/// ```
+pub fn rate_limit(
+    requests: u32,
+    per: Duration,
+) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+    let buckets: Arc<Mutex<Buckets>> = Arc::new(Mutex::new(Buckets {
+        map: HashMap::new(),
+        sweep: Instant::now(),
+    }));
+
+    let capacity: f64 = requests.max(1) as f64;
+    /*
+     * Tokens refilled per Second
+     */
+    let rate: f64 = capacity / per.as_secs_f64().max(f64::EPSILON);
+
+    move |mut c: Context| {
+        let buckets: Arc<Mutex<Buckets>> = buckets.clone();
+
+        Box::pin(async move {
+            let now: Instant = Instant::now();
+
+            let retry_after: Option<u64> = {
+                let mut buckets: MutexGuard<Buckets> =
+                    buckets.lock().unwrap_or_else(|e| e.into_inner());
+                /*
+                 * Evict Buckets idle for the whole Duration (full again)
+                 */
+                if now.duration_since(buckets.sweep) >= per {
+                    buckets
+                        .map
+                        .retain(|_, x: &mut Bucket| now.duration_since(x.last) < per);
+                    buckets.sweep = now;
+                }
+
+                let bucket: &mut Bucket =
+                    buckets
+                        .map
+                        .entry(c.request.remote_addr.ip())
+                        .or_insert(Bucket {
+                            tokens: capacity,
+                            last: now,
+                        });
+
+                bucket.tokens = (bucket.tokens
+                    + now.duration_since(bucket.last).as_secs_f64() * rate)
+                    .min(capacity);
+                bucket.last = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    None
+                } else {
+                    Some(((1.0 - bucket.tokens) / rate).ceil() as u64)
+                }
+            };
+
+            match retry_after {
+                None => c.next = true,
+                Some(x) => {
+                    c.response.status = 429;
+                    c.response.body = "Too Many Requests".to_owned();
+                    c.response
+                        .set_header("Retry-After", &x.max(1).to_string())
+                        .await;
+                }
+            }
+
+            (c, None)
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::utils::uuid_v4::uuid_v4;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+/*
+ * ID of the Request, read with `Context::request_id`
+ */
+pub(crate) struct RequestId(pub(crate) String);
+
+/// Request ID Middleware Builder
+///
+/// Takes the ID from the Request header (Default: `X-Request-Id`) or
+/// generates one (Default: random UUID v4), exposes it with
+/// `Context::request_id` & sends it back in the same Response header. The ID
+/// stays the same for the whole Request, including the Functions & Tails of
+/// later Middlewares (e.g. for Logs). Incoming IDs longer than 200 characters
+/// or with characters other than visible ASCII are replaced.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route, middleware};
+/// use oxidy::middlewares::request_id::RequestIds;
+///
+/// async fn route(mut c: Context) -> Returns {
+///     let id: String = c.request_id().await.unwrap_or_default();
+///     c.response.body = format!("Request: {}", id);
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(RequestIds::new()
+///     .header_name("X-Correlation-Id")
+///     .generator(|| format!("req-{}", std::process::id()))
+///     .middleware()));
+/// app.add(route!("get /", route));
+/// ```
+#[derive(Clone)]
+pub struct RequestIds {
+    header_name: String,
+    generator: Arc<dyn Fn() -> String + Send + Sync>,
+}
+
+impl Default for RequestIds {
+    fn default() -> RequestIds {
+        RequestIds {
+            header_name: "X-Request-Id".to_owned(),
+            generator: Arc::new(uuid_v4),
+        }
+    }
+}
+
+impl RequestIds {
+    /// New Request ID Builder (header `X-Request-Id`, UUID v4)
+    pub fn new() -> RequestIds {
+        Default::default()
+    }
+    /// Set the Request & Response header carrying the ID
+    pub fn header_name(mut self, name: &str) -> RequestIds {
+        self.header_name = name.to_owned();
+        self
+    }
+    /// Set the Function generating IDs for Requests without one
+    pub fn generator<F>(mut self, func: F) -> RequestIds
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.generator = Arc::new(func);
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let request_ids: Arc<RequestIds> = Arc::new(self);
+
+        move |c: Context| {
+            let request_ids: Arc<RequestIds> = request_ids.clone();
+            Box::pin(async move { request_ids.handle(c).await })
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut c: Context) -> Returns {
+        c.next = true;
+        /*
+         * Incoming ID or a new one
+         */
+        let id: String = match c.request.header(&self.header_name).await {
+            Some(x)
+                if !x.is_empty()
+                    && x.len() <= 200
+                    && x.chars().all(|x: char| x.is_ascii_graphic()) =>
+            {
+                x
+            }
+            _ => (self.generator)(),
+        };
+
+        c.response.set_header(&self.header_name, &id).await;
+        c.set_ext(RequestId(id)).await;
+
+        (c, None)
+    }
+}
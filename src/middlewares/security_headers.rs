@@ -0,0 +1,174 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use futures::future::BoxFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Security Headers Middleware with the Defaults
+///
+/// Same as `SecurityHeaders::new().middleware()`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::security_headers::security_headers;
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(security_headers()));
+/// ```
+pub fn security_headers() -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+    SecurityHeaders::new().middleware()
+}
+
+/// Security Headers Middleware Builder
+///
+/// Sets hardening headers on every Response after the Route (also `404` &
+/// short-circuited ones): `X-Content-Type-Options: nosniff`,
+/// `X-Frame-Options` (Default: `DENY`), `Referrer-Policy` (Default:
+/// `strict-origin-when-cross-origin`) & optionally `Content-Security-Policy`
+/// & `Strict-Transport-Security`. Headers the Functions set are kept, an
+/// empty value omits the header.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route, middleware};
+/// use oxidy::middlewares::security_headers::SecurityHeaders;
+/// use std::net::SocketAddr;
+/// use std::time::Duration;
+/// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+///
+/// async fn embed(mut c: Context) -> Returns {
+///     c.response.set_header("X-Frame-Options", "SAMEORIGIN").await;
+///     c.response.body = "Embeddable".to_owned();
+///     (c, None)
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let mut app = Server::new();
+/// app.add(middleware!(SecurityHeaders::new()
+///     .content_security_policy("default-src 'self'")
+///     .strict_transport_security(Duration::from_secs(31536000), true)
+///     .referrer_policy("no-referrer")
+///     .middleware()));
+/// app.add(route!("get /embed", embed));
+///
+/// let (mut client, stream) = duplex(1024);
+///
+/// client.write_all(b"GET /embed HTTP/1.1\r\n\r\n").await.unwrap();
+/// client.shutdown().await.unwrap();
+///
+/// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+///
+/// let mut response: String = String::new();
+/// client.read_to_string(&mut response).await.unwrap();
+///
+/// assert!(response.contains("X-Content-Type-Options: nosniff\r\n"));
+/// assert!(response.contains("X-Frame-Options: SAMEORIGIN\r\n"));
+/// assert!(!response.contains("X-Frame-Options: DENY"));
+/// assert!(response.contains("Referrer-Policy: no-referrer\r\n"));
+/// assert!(response.contains("Content-Security-Policy: default-src 'self'\r\n"));
+/// assert!(response.contains("Strict-Transport-Security: max-age=31536000; includeSubDomains\r\n"));
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SecurityHeaders {
+    frame_options: String,
+    referrer_policy: String,
+    content_security_policy: String,
+    strict_transport_security: String,
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> SecurityHeaders {
+        SecurityHeaders {
+            frame_options: "DENY".to_owned(),
+            referrer_policy: "strict-origin-when-cross-origin".to_owned(),
+            content_security_policy: String::new(),
+            strict_transport_security: String::new(),
+        }
+    }
+}
+
+impl SecurityHeaders {
+    /// New Security Headers Builder (`nosniff`, `DENY`,
+    /// `strict-origin-when-cross-origin`, no CSP & HSTS)
+    pub fn new() -> SecurityHeaders {
+        Default::default()
+    }
+    /// Set `X-Frame-Options`, e.g. `SAMEORIGIN`
+    pub fn frame_options(mut self, value: &str) -> SecurityHeaders {
+        self.frame_options = value.to_owned();
+        self
+    }
+    /// Set `Referrer-Policy`, e.g. `no-referrer`
+    pub fn referrer_policy(mut self, value: &str) -> SecurityHeaders {
+        self.referrer_policy = value.to_owned();
+        self
+    }
+    /// Set `Content-Security-Policy`, e.g. `default-src 'self'`
+    pub fn content_security_policy(mut self, value: &str) -> SecurityHeaders {
+        self.content_security_policy = value.to_owned();
+        self
+    }
+    /// Set `Strict-Transport-Security` (only serve it over HTTPS)
+    pub fn strict_transport_security(
+        mut self,
+        max_age: Duration,
+        include_subdomains: bool,
+    ) -> SecurityHeaders {
+        self.strict_transport_security = format!(
+            "max-age={}{}",
+            max_age.as_secs(),
+            if include_subdomains {
+                "; includeSubDomains"
+            } else {
+                ""
+            }
+        );
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let security_headers: Arc<SecurityHeaders> = Arc::new(self);
+
+        move |c: Context| {
+            let security_headers: Arc<SecurityHeaders> = security_headers.clone();
+            Box::pin(async move { security_headers.handle(c).await })
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut c: Context) -> Returns {
+        c.next = true;
+
+        (
+            c,
+            Some(Box::new(move |c: Context| {
+                let security_headers: Arc<SecurityHeaders> = self.clone();
+                Box::pin(async move { security_headers.set(c).await })
+            })),
+        )
+    }
+    /*
+     * Set the Headers the Functions did not set
+     */
+    async fn set(&self, mut c: Context) -> Context {
+        let headers: [(&str, &str); 5] = [
+            ("X-Content-Type-Options", "nosniff"),
+            ("X-Frame-Options", &self.frame_options),
+            ("Referrer-Policy", &self.referrer_policy),
+            ("Content-Security-Policy", &self.content_security_policy),
+            ("Strict-Transport-Security", &self.strict_transport_security),
+        ];
+
+        for (k, v) in headers {
+            if !v.is_empty() && c.response.get_header(k).await.is_none() {
+                c.response.set_header(k, v).await;
+            }
+        }
+
+        c
+    }
+}
@@ -0,0 +1,182 @@
+use crate::structs::context::Context;
+use crate::structs::cookie::{Cookie, SameSite};
+use crate::structs::definition::Returns;
+use crate::structs::session::{MemoryStore, Session, SessionData, SessionState, SessionStore};
+use crate::utils::random_token::random_token;
+use futures::future::BoxFuture;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::{Arc, MutexGuard};
+use std::time::Duration;
+
+/// Session Middleware Builder
+///
+/// Loads the Session of the Session ID Cookie from the Store & exposes it
+/// with `Context::session`. After the Route, changed Sessions are saved &
+/// the Cookie (`HttpOnly`, `SameSite=Lax`, `Path=/`) is set, destroyed ones
+/// are deleted with an expired Cookie. Session IDs are 32 random Bytes from
+/// the OS (hex), unknown or malformed IDs start a new Session & new Sessions
+/// only get a Cookie once a Value is set.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, middleware};
+/// use oxidy::middlewares::session::Sessions;
+/// use oxidy::structs::session::MemoryStore;
+/// use std::time::Duration;
+///
+/// let mut app = Server::new();
+/// app.add(middleware!(Sessions::new()
+///     .store(MemoryStore::new())
+///     .cookie_name("sid")
+///     .max_age(Duration::from_secs(3600))
+///     .secure(true)
+///     .middleware()));
+/// ```
+#[derive(Clone)]
+pub struct Sessions {
+    store: Arc<dyn SessionStore>,
+    cookie_name: String,
+    max_age: Duration,
+    secure: bool,
+    same_site: SameSite,
+}
+
+impl Default for Sessions {
+    fn default() -> Sessions {
+        Sessions {
+            store: Arc::new(MemoryStore::new()),
+            cookie_name: "session".to_owned(),
+            max_age: Duration::from_secs(24 * 60 * 60),
+            secure: false,
+            same_site: SameSite::Lax,
+        }
+    }
+}
+
+impl Sessions {
+    /// New Session Builder (Memory Store, Cookie `session`, 24 hours)
+    pub fn new() -> Sessions {
+        Default::default()
+    }
+    /// Set the Session Store
+    pub fn store(mut self, store: impl SessionStore) -> Sessions {
+        self.store = Arc::new(store);
+        self
+    }
+    /// Set the Cookie Name
+    pub fn cookie_name(mut self, name: &str) -> Sessions {
+        self.cookie_name = name.to_owned();
+        self
+    }
+    /// Session Lifetime after the last Change (Cookie `Max-Age` & Store TTL)
+    pub fn max_age(mut self, duration: Duration) -> Sessions {
+        self.max_age = duration;
+        self
+    }
+    /// Send the Cookie over HTTPS only (`Secure`)
+    pub fn secure(mut self, secure: bool) -> Sessions {
+        self.secure = secure;
+        self
+    }
+    /// Set the Cookie `SameSite` Attribute (Default: `Lax`)
+    pub fn same_site(mut self, same_site: SameSite) -> Sessions {
+        self.same_site = same_site;
+        self
+    }
+    /// Build the Middleware Function
+    pub fn middleware(self) -> impl Fn(Context) -> BoxFuture<'static, Returns> + Send + Sync {
+        let sessions: Arc<Sessions> = Arc::new(self);
+
+        move |c: Context| {
+            let sessions: Arc<Sessions> = sessions.clone();
+            Box::pin(async move { sessions.handle(c).await })
+        }
+    }
+
+    async fn handle(self: Arc<Self>, mut c: Context) -> Returns {
+        c.next = true;
+        /*
+         * Known Session or a new one
+         */
+        let id: Option<String> = c
+            .request
+            .cookie(&self.cookie_name)
+            .await
+            .filter(|x: &String| x.len() == 64 && x.chars().all(|x: char| x.is_ascii_hexdigit()));
+
+        let loaded: Option<(String, SessionData)> = match id {
+            Some(x) => self.store.load(&x).await.map(|data: SessionData| (x, data)),
+            None => None,
+        };
+
+        let session: Session = match loaded {
+            Some((id, data)) => Session::new(Some(id), data),
+            None => Session::new(None, SessionData::new()),
+        };
+
+        c.set_ext(session.clone()).await;
+
+        (
+            c,
+            Some(Box::new(move |c: Context| {
+                let sessions: Arc<Sessions> = self.clone();
+                let session: Session = session.clone();
+                Box::pin(async move { sessions.save(c, session).await })
+            })),
+        )
+    }
+    /*
+     * Save or destroy the Session & set its Cookie
+     */
+    async fn save(&self, mut c: Context, session: Session) -> Context {
+        let (replaced, save, destroyed): (Option<String>, Option<(String, SessionData)>, bool) = {
+            let mut state: MutexGuard<SessionState> = session.state();
+
+            let save: Option<(String, SessionData)> = if state.changed {
+                let id: String = state.id.get_or_insert_with(|| random_token(32)).clone();
+                state.changed = false;
+                Some((id, state.data.clone()))
+            } else {
+                None
+            };
+
+            (state.replaced.take(), save, state.destroyed)
+        };
+
+        if let Some(x) = replaced {
+            self.store.destroy(&x).await;
+        }
+
+        if let Some((id, data)) = save {
+            self.store.save(&id, data, self.max_age).await;
+            c.response
+                .set_cookie(self.cookie(&id, self.max_age.as_secs() as i64))
+                .await;
+        } else if destroyed {
+            c.response.set_cookie(self.cookie("", 0)).await;
+        }
+
+        c
+    }
+
+    fn cookie(&self, value: &str, max_age: i64) -> Cookie {
+        Cookie::new(&self.cookie_name, value)
+            .path("/")
+            .max_age(max_age)
+            .http_only(true)
+            .secure(self.secure)
+            .same_site(self.same_site)
+    }
+}
+
+impl Debug for Sessions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Sessions")
+            .field("cookie_name", &self.cookie_name)
+            .field("max_age", &self.max_age)
+            .field("secure", &self.secure)
+            .field("same_site", &self.same_site)
+            .finish()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::structs::definition::Callback;
+use crate::utils::check_path::check_path;
+use crate::utils::join_path::join_path;
+use std::sync::Arc;
+
+#[derive(Default, Clone)]
+pub struct Router {
+    pub(crate) adds: Vec<(String, String, Vec<Arc<Callback>>)>,
+}
+
+impl Router {
+    /// New Router Instence
+    ///
+    /// Group of Routes / Middlewares to mount under a shared path prefix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Router;
+    ///
+    /// let mut router = Router::new();
+    /// ```
+    pub fn new() -> Router {
+        Default::default()
+    }
+    /// Add Routes / Middlewares
+    ///
+    /// Middlewares added to a Router only run for paths under its prefix.
+    /// Returns the Router, so registrations can be chained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Router, Context, Returns, route};
+    ///
+    /// async fn users(mut c: Context) -> Returns {
+    ///     c.response.body = "Users".to_owned();
+    ///     (c, None)
+    /// }
+    ///
+    /// async fn create(mut c: Context) -> Returns {
+    ///     c.response.status = 201;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut router = Router::new();
+    /// router
+    ///     .add(route!("get /users", users))
+    ///     .add(route!("post /users", create));
+    /// ```
+    pub fn add(&mut self, args: (&str, &str, Vec<Arc<Callback>>)) -> &mut Router {
+        check_path(args.1);
+
+        self.adds
+            .push((args.0.to_owned(), args.1.to_owned(), args.2));
+        self
+    }
+    /// Mount Nested Router
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Router, Context, Returns, route};
+    ///
+    /// async fn users(mut c: Context) -> Returns {
+    ///     c.response.body = "Users".to_owned();
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut v1 = Router::new();
+    /// v1.add(route!("get /users", users));
+    ///
+    /// /* GET /v1/users */
+    /// let mut api = Router::new();
+    /// api.mount("/v1", v1);
+    /// ```
+    pub fn mount(&mut self, prefix: &str, router: Router) -> &mut Router {
+        router.adds.into_iter().for_each(|(method, path, funcs)| {
+            self.adds.push((method, join_path(prefix, &path), funcs));
+        });
+
+        self
+    }
+}
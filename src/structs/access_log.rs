@@ -0,0 +1,193 @@
+use crate::structs::context::Context;
+use crate::utils::http_date::http_date;
+use std::fs::{File, OpenOptions};
+use std::io::Error;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, Once};
+use std::time::SystemTime;
+use tokio::fs::{File as AsyncFile, OpenOptions as AsyncOpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::spawn;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot::{channel, Sender};
+
+/*
+ * Access Log File in Combined Log Format
+ * Lines are sent to one Writer Task, which is started on first use & flushes
+ * whenever no Lines are waiting. The File is closed after the last Clone is
+ * dropped.
+ */
+#[derive(Clone)]
+pub(crate) struct AccessLog {
+    sender: UnboundedSender<Command>,
+    start: Arc<Once>,
+    pending: Arc<Mutex<Option<Pending>>>,
+}
+/*
+ * Writer Task Arguments until it is started
+ */
+type Pending = (PathBuf, File, UnboundedReceiver<Command>);
+
+enum Command {
+    Line(String),
+    Flush(Sender<Result<(), Error>>),
+    Reopen(Sender<Result<(), Error>>),
+}
+
+impl AccessLog {
+    pub(crate) fn open(path: &str) -> Result<AccessLog, Error> {
+        let (sender, receiver): (UnboundedSender<Command>, UnboundedReceiver<Command>) =
+            unbounded_channel();
+
+        Ok(AccessLog {
+            sender,
+            start: Arc::new(Once::new()),
+            pending: Arc::new(Mutex::new(Some((
+                PathBuf::from(path),
+                append(path)?,
+                receiver,
+            )))),
+        })
+    }
+    /*
+     * Append the Line of a written Response
+     */
+    pub(crate) async fn write(&self, c: &mut Context) {
+        let bytes: usize = c.response_size().await;
+
+        let header = |value: Option<String>| -> String {
+            format!(
+                "\"{}\"",
+                value.map_or("-".to_owned(), |x: String| escape(&x))
+            )
+        };
+
+        let line: String = format!(
+            "{} - {} [{}] \"{}\" {} {} {} {}\n",
+            c.request.remote_addr().await.ip(),
+            c.get_state("user")
+                .await
+                .map_or("-".to_owned(), |x: String| escape(&x)),
+            clf_date(SystemTime::now()),
+            escape(&format!(
+                "{} {} HTTP/{:.1}",
+                c.request.method.to_uppercase(),
+                c.request.url,
+                c.request.http_version
+            )),
+            c.response.status,
+            if bytes > 0 {
+                bytes.to_string()
+            } else {
+                "-".to_owned()
+            },
+            header(c.request.header("referer").await),
+            header(c.request.header("user-agent").await),
+        );
+
+        self.send(Command::Line(line));
+    }
+
+    pub(crate) async fn flush(&self) -> Result<(), Error> {
+        let (sender, receiver) = channel();
+
+        self.send(Command::Flush(sender));
+
+        receiver.await.unwrap_or(Ok(()))
+    }
+    /*
+     * Reopen the File after it was moved (logrotate)
+     */
+    pub(crate) async fn reopen(&self) -> Result<(), Error> {
+        let (sender, receiver) = channel();
+
+        self.send(Command::Reopen(sender));
+
+        receiver.await.unwrap_or(Ok(()))
+    }
+    /*
+     * Send to the Writer Task, started on the first Command
+     */
+    fn send(&self, command: Command) {
+        self.start.call_once(|| {
+            if let Some((path, file, receiver)) = self
+                .pending
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take()
+            {
+                spawn(writer(path, AsyncFile::from_std(file), receiver));
+            }
+        });
+
+        self.sender.send(command).ok();
+    }
+}
+/*
+ * Write Lines until every Sender is dropped, then flush & close the File
+ */
+async fn writer(path: PathBuf, file: AsyncFile, mut receiver: UnboundedReceiver<Command>) {
+    let mut file: BufWriter<AsyncFile> = BufWriter::new(file);
+
+    while let Some(command) = receiver.recv().await {
+        match command {
+            Command::Line(x) => {
+                file.write_all(x.as_bytes()).await.ok();
+            }
+            Command::Flush(x) => {
+                x.send(file.flush().await).ok();
+            }
+            Command::Reopen(x) => {
+                file.flush().await.ok();
+
+                let reopened: Result<AsyncFile, Error> = AsyncOpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .await;
+
+                x.send(reopened.map(|new: AsyncFile| file = BufWriter::new(new)))
+                    .ok();
+            }
+        }
+        /*
+         * Lines are batched while more are waiting
+         */
+        if receiver.is_empty() {
+            file.flush().await.ok();
+        }
+    }
+
+    file.flush().await.ok();
+}
+
+fn append(path: impl Into<PathBuf>) -> Result<File, Error> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path.into())
+}
+/*
+ * Common Log Format Time, e.g. `10/Oct/2000:13:55:36 +0000`
+ * Reordered from the HTTP Date `Tue, 10 Oct 2000 13:55:36 GMT`
+ */
+fn clf_date(time: SystemTime) -> String {
+    let date: String = http_date(time);
+    let parts: Vec<&str> = date.split(' ').collect();
+
+    format!("{}/{}/{}:{} +0000", parts[1], parts[2], parts[3], parts[4])
+}
+/*
+ * Escape Backslash, Double Quote & Control Characters
+ */
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .map(|x: char| match x {
+            '\\' => "\\\\".to_owned(),
+            '"' => "\\\"".to_owned(),
+            x if x.is_control() => format!("\\x{:02x}", x as u32),
+            x => x.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// Request Body Error
+///
+/// Returned by `Request::body`, `form` & `form_field`, see `Request::body`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::body_error::BodyError;
+///
+/// async fn route(mut c: Context) -> Returns {
+///     let body: Result<Vec<u8>, BodyError> = c.request.body().await;
+///     match body {
+///         Ok(x) => c.response.body = format!("Received {} bytes", x.len()),
+///         Err(e) => {
+///             c.response.body = e.to_string();
+///             c.response.status = 500;
+///         }
+///     }
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("post /", route));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum BodyError {
+    /// Streamed Request Body was consumed by `body_reader` or `multipart_upload`
+    Consumed,
+}
+
+impl Display for BodyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            BodyError::Consumed => write!(f, "Request body already consumed"),
+        }
+    }
+}
+
+impl Error for BodyError {}
@@ -0,0 +1,132 @@
+use bytes::Bytes;
+use std::cmp::min;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context as TaskContext, Poll};
+use tokio::io::{AsyncRead, Error, ReadBuf};
+use tokio::sync::mpsc::Receiver;
+
+/*
+ * Request Body Chunks sent while the Request is dispatched
+ */
+pub(crate) type BodyChunks = Receiver<Result<Vec<u8>, Error>>;
+
+/// Request Body Reader
+///
+/// Reads the Request Body as it arrives (`Server::stream_body`) or from the
+/// buffered Body. Implements `AsyncRead`, ends after `Content-Length` bytes &
+/// errors if the connection fails, the Read Timeout elapses or the streamed
+/// Body was already consumed. See `Request::body_reader`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::body_reader::BodyReader;
+/// use tokio::io::AsyncReadExt;
+///
+/// async fn upload(mut c: Context) -> Returns {
+///     let mut reader: BodyReader = c.request.body_reader().await;
+///     let mut chunk: [u8; 8192] = [0; 8192];
+///     let mut total: usize = 0;
+///
+///     loop {
+///         match reader.read(&mut chunk).await {
+///             Ok(0) => break,
+///             Ok(n) => total += n,
+///             Err(_) => {
+///                 c.response.status = 400;
+///                 return (c, None);
+///             }
+///         }
+///     }
+///
+///     c.response.body = format!("Received {} bytes", total);
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.stream_body(true);
+/// app.add(route!("post /upload", upload));
+/// ```
+pub struct BodyReader {
+    pub(crate) buffer: Bytes,
+    pub(crate) position: usize,
+    pub(crate) chunks: Option<BodyChunks>,
+    pub(crate) consumed: bool,
+}
+/*
+ * Streamed Body, taken once by the first Clone reading it
+ */
+#[derive(Clone)]
+pub(crate) struct BodyStream(pub(crate) Arc<Mutex<Option<BodyChunks>>>);
+
+impl BodyStream {
+    pub(crate) fn take(&self) -> Option<BodyChunks> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+impl Debug for BodyStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("BodyStream")
+    }
+}
+
+impl Debug for BodyReader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("BodyReader")
+    }
+}
+
+impl AsyncRead for BodyReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<(), Error>> {
+        let this: &mut BodyReader = self.get_mut();
+
+        if this.consumed {
+            return Poll::Ready(Err(Error::other("Request body already consumed")));
+        }
+
+        loop {
+            /*
+             * Buffered Bytes first
+             */
+            if this.position < this.buffer.len() {
+                let n: usize = min(buf.remaining(), this.buffer.len() - this.position);
+
+                buf.put_slice(&this.buffer[this.position..this.position + n]);
+                this.position += n;
+
+                return Poll::Ready(Ok(()));
+            }
+            /*
+             * Next Chunk, End of Body when the Sender is done
+             */
+            let chunks: &mut BodyChunks = match this.chunks.as_mut() {
+                Some(x) => x,
+                None => return Poll::Ready(Ok(())),
+            };
+
+            match chunks.poll_recv(cx) {
+                Poll::Ready(Some(Ok(x))) => {
+                    this.buffer = Bytes::from(x);
+                    this.position = 0;
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.chunks = None;
+                    return Poll::Ready(Err(e));
+                }
+                Poll::Ready(None) => {
+                    this.chunks = None;
+                    return Poll::Ready(Ok(()));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
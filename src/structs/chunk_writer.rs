@@ -0,0 +1,72 @@
+use tokio::io::{Error, ErrorKind};
+use tokio::sync::mpsc::Sender;
+
+/// Chunk Writer of a streamed Response
+///
+/// Each write is sent to the client as one chunk
+/// (`Transfer-Encoding: chunked`) as soon as it is produced. See
+/// `Response::stream`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::chunk_writer::ChunkWriter;
+///
+/// async fn route(mut c: Context) -> Returns {
+///     c.response
+///         .stream(|mut writer: ChunkWriter| async move {
+///             for i in 0..3 {
+///                 writer.write(format!("line {}\n", i)).await?;
+///             }
+///             Ok(())
+///         })
+///         .await;
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("get /lines", route));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkWriter {
+    pub(crate) sender: Sender<Vec<u8>>,
+}
+
+impl ChunkWriter {
+    /// Write a Chunk
+    ///
+    /// Waits while the client is slower than the producer. Empty data is
+    /// skipped. Returns a `BrokenPipe` Error once the client is gone, so the
+    /// producer can stop.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::chunk_writer::ChunkWriter;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response
+    ///         .stream(|mut writer: ChunkWriter| async move {
+    ///             writer.write("Hello ").await?;
+    ///             writer.write(b"World").await
+    ///         })
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn write(&mut self, data: impl AsRef<[u8]>) -> Result<(), Error> {
+        if data.as_ref().is_empty() {
+            return Ok(());
+        }
+
+        self.sender
+            .send(data.as_ref().to_vec())
+            .await
+            .map_err(|_| Error::new(ErrorKind::BrokenPipe, "Client Disconnected"))
+    }
+}
@@ -0,0 +1,11 @@
+use std::sync::{Arc, RwLock};
+
+/*
+ * Configuration of `Server::with_config`, shared by Server Clones
+ * `Server::reload_config` swaps the current Snapshot
+ */
+pub(crate) struct ConfigCell<T>(pub(crate) RwLock<Arc<T>>);
+/*
+ * Snapshot of a Request, taken on the first `Context::config`
+ */
+pub(crate) struct ConfigSnapshot<T>(pub(crate) Arc<T>);
@@ -1,8 +1,48 @@
+use crate::middlewares::csrf::CsrfToken;
+use crate::middlewares::request_id::RequestId;
+use crate::structs::config::{ConfigCell, ConfigSnapshot};
+use crate::structs::cookie::{Cookie, SecretKey};
+use crate::structs::extensions::Extensions;
+use crate::structs::http_version::HttpVersion;
+use crate::structs::method::Method;
+use crate::structs::payload::Payload;
+#[cfg(feature = "websocket")]
+use crate::structs::payload::Upgrade;
 use crate::structs::request::Request;
 use crate::structs::response::Response;
+use crate::structs::session::Session;
+use crate::structs::status_code::StatusCode;
+#[cfg(feature = "templates")]
+use crate::structs::templates::Templates;
+#[cfg(feature = "websocket")]
+use crate::structs::websocket::WebSocket;
 use crate::utils::del_vec::del_vec;
 use crate::utils::get_vec::get_vec;
+use crate::utils::parse_http_version::parse_http_version;
+use crate::utils::parse_method::parse_method;
+use crate::utils::parse_path::parse_path;
+use crate::utils::percent_decode::percent_decode;
 use crate::utils::set_vec::set_vec;
+#[cfg(feature = "websocket")]
+use base64::engine::general_purpose::STANDARD;
+#[cfg(feature = "websocket")]
+use base64::Engine;
+use bytes::Bytes;
+#[cfg(feature = "websocket")]
+use futures::future::BoxFuture;
+#[cfg(feature = "templates")]
+use handlebars::{RenderError, RenderErrorReason};
+#[cfg(feature = "websocket")]
+use sha1::{Digest, Sha1};
+use std::any::type_name;
+#[cfg(feature = "websocket")]
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+#[cfg(feature = "websocket")]
+use std::sync::Mutex;
+use tokio::join;
 
 #[derive(Clone, Debug)]
 pub struct Context {
@@ -26,11 +66,160 @@ pub struct Context {
     /// ```
     pub next: bool,
     pub(crate) state: Vec<(String, String)>,
+    pub(crate) extensions: Extensions,
+    pub(crate) states: Arc<Extensions>,
+    pub(crate) matched_route: Option<String>,
+    pub(crate) response_size: Arc<AtomicUsize>,
     pub request: Request,
     pub response: Response,
 }
 
 impl Context {
+    /*
+     * New Context for a Request Header read from the Connection
+     */
+    pub(crate) async fn new(
+        address: SocketAddr,
+        header: String,
+        states: Arc<Extensions>,
+    ) -> Context {
+        let ((url, path, query), method, http_version) = join!(
+            parse_path(&header),
+            parse_method(&header),
+            parse_http_version(&header)
+        );
+
+        Context {
+            next: true,
+            state: Vec::new(),
+            extensions: Extensions::default(),
+            states,
+            matched_route: None,
+            response_size: Arc::new(AtomicUsize::new(0)),
+            request: Request {
+                address: address.to_string(),
+                remote_addr: address,
+                header,
+                header_store: Vec::new(),
+                param_store: Vec::new(),
+                query_store: Vec::new(),
+                method,
+                url,
+                path: percent_decode(&path, false),
+                raw_path: path,
+                query,
+                http_version,
+                body: Bytes::new(),
+                body_stream: None,
+                body_consumed: false,
+            },
+            response: Response {
+                header: Vec::new(),
+                body: String::new(),
+                status: 200,
+                content_type: String::new(),
+                payload: None,
+            },
+        }
+    }
+    /// New Context for Testing a Function without a Connection
+    ///
+    /// Builds an `HTTP/1.1` Request from `127.0.0.1` with a `Content-Length`
+    /// header for the body, more headers are added with
+    /// `request.append_header`. No Application State is registered. The query
+    /// string of the path is parsed, path parameters are not (no Route is
+    /// matched). Call the Function with the Context & inspect the returned one
+    /// with `response_status` & `response_body`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Context, Returns};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let name: String = c.request.query("name").await.unwrap_or_default();
+    ///     let body: Vec<u8> = c.request.body().await.unwrap_or_default();
+    ///     c.response.status = 201;
+    ///     c.response.body = format!("{} {}", name, String::from_utf8_lossy(&body));
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("post", "/user?name=John", "Doe").await;
+    /// c.request.append_header("Authorization", "Bearer token");
+    ///
+    /// assert_eq!(c.request.method, "POST");
+    /// assert_eq!(c.request.path, "/user");
+    /// assert_eq!(
+    ///     c.request.header("authorization").await,
+    ///     Some("Bearer token".to_owned())
+    /// );
+    ///
+    /// let (c, _) = route(c).await;
+    ///
+    /// assert_eq!(c.response_status().await, 201);
+    /// assert_eq!(c.response_body().await, "John Doe");
+    /// # }
+    /// ```
+    pub async fn test_request(method: &str, path: &str, body: &str) -> Context {
+        let header: String = format!(
+            "{} {} HTTP/1.1\r\nContent-Length: {}",
+            method.to_uppercase(),
+            path,
+            body.len()
+        );
+
+        let mut context: Context = Context::new(
+            SocketAddr::from(([127, 0, 0, 1], 0)),
+            header,
+            Arc::new(Extensions::default()),
+        )
+        .await;
+
+        context.request.body = Bytes::from(body.as_bytes().to_vec());
+
+        context
+    }
+    /// Get Response Status
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// assert_eq!(c.response_status().await, 200);
+    ///
+    /// c.response.status = 404;
+    /// assert_eq!(c.response_status().await, 404);
+    /// # }
+    /// ```
+    pub async fn response_status(&self) -> usize {
+        self.response.status
+    }
+    /// Get Response Body
+    ///
+    /// Bodies streamed with `send_file`, `stream` or `sse` are written to the
+    /// Connection & not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// c.response.body = "Hello World".to_owned();
+    /// assert_eq!(c.response_body().await, "Hello World");
+    /// # }
+    /// ```
+    pub async fn response_body(&self) -> String {
+        self.response.body.clone()
+    }
     /// Get State
     ///
     /// State of Key Value pair to transfer data between Middlewares or Routes
@@ -130,4 +319,989 @@ impl Context {
     pub async fn del_state(&mut self, key: &str) {
         self.state = del_vec(&self.state, key.to_owned()).await;
     }
+    /// Set Response Header
+    ///
+    /// Replaces any existing value of the header, use
+    /// `response.append_header` to keep them (e.g. multiple `Set-Cookie`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.set_header("Cache-Control", "no-store").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn set_header(&mut self, key: &str, value: &str) {
+        self.response.set_header(key, value).await;
+    }
+    /// Set Extension
+    ///
+    /// Typed Value to transfer data between Middlewares or Routes, e.g. the
+    /// authenticated User. One Value per Type, setting it again replaces it.
+    /// Extensions belong to the Request & are dropped after its Response,
+    /// Requests on a Keep-Alive connection start without any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route, middleware};
+    ///
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn mid(mut c: Context) -> Returns {
+    ///     c.set_ext(User {
+    ///         name: "John Doe".to_owned(),
+    ///     })
+    ///     .await;
+    ///     c.next = true;
+    ///     (c, None)
+    /// }
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let name: Option<String> = c.get_ext::<User>().await.map(|u: &User| u.name.clone());
+    ///     match name {
+    ///         Some(u) => c.response.body = format!("Username: {}", u),
+    ///         None => c.response.status = 401,
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(mid));
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn set_ext<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.extensions.insert(value);
+    }
+    /// Get Extension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// struct RequestId(u64);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// assert!(c.get_ext::<RequestId>().await.is_none());
+    ///
+    /// c.set_ext(RequestId(7)).await;
+    /// assert_eq!(c.get_ext::<RequestId>().await.map(|x: &RequestId| x.0), Some(7));
+    /// # }
+    /// ```
+    pub async fn get_ext<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get::<T>()
+    }
+    /// Delete Extension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// struct RequestId(u64);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// c.set_ext(RequestId(7)).await;
+    ///
+    /// c.del_ext::<RequestId>().await;
+    /// assert!(c.get_ext::<RequestId>().await.is_none());
+    /// # }
+    /// ```
+    pub async fn del_ext<T: Send + Sync + 'static>(&mut self) {
+        self.extensions.remove::<T>();
+    }
+    /// Get Request Method
+    ///
+    /// Typed `request.method` (after `Server::method_override`), unknown
+    /// Methods are `Method::Other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, Method, middleware};
+    ///
+    /// async fn read_only(mut c: Context) -> Returns {
+    ///     match c.method().await {
+    ///         Method::Get | Method::Head | Method::Options => c.next = true,
+    ///         _ => c.response.status = 403,
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(read_only));
+    /// ```
+    ///
+    /// Unknown Method
+    ///
+    /// ```
+    /// use oxidy::{Context, Method};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let c: Context = Context::test_request("propfind", "/", "").await;
+    /// assert_eq!(c.method().await, Method::Other("PROPFIND".to_owned()));
+    /// # }
+    /// ```
+    pub async fn method(&self) -> Method {
+        Method::from(self.request.method.as_str())
+    }
+    /// Get Request HTTP Version
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    /// use oxidy::structs::http_version::HttpVersion;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let c: Context = Context::test_request("get", "/", "").await;
+    /// assert_eq!(c.version().await, HttpVersion::Http11);
+    /// # }
+    /// ```
+    pub async fn version(&self) -> HttpVersion {
+        HttpVersion::from(self.request.http_version)
+    }
+    /// Get Request Path
+    ///
+    /// Percent-decoded Path without Query & Fragment (`request.path`), see
+    /// `request.raw_path` for the Path as received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let c: Context = Context::test_request("get", "/caf%C3%A9?a=1#top", "").await;
+    /// assert_eq!(c.path().await, "/café");
+    /// # }
+    /// ```
+    pub async fn path(&self) -> &str {
+        &self.request.path
+    }
+    /// Get Query String
+    ///
+    /// Raw (not decoded) Query after the first `?`, without the Fragment.
+    /// Empty without a Query.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let c: Context = Context::test_request("get", "/search?q=a%20b&next=/x?y#top", "").await;
+    /// assert_eq!(c.query_string().await, "q=a%20b&next=/x?y");
+    ///
+    /// let c: Context = Context::test_request("get", "/search", "").await;
+    /// assert_eq!(c.query_string().await, "");
+    /// # }
+    /// ```
+    pub async fn query_string(&self) -> &str {
+        &self.request.query
+    }
+    /// Get Request Target
+    ///
+    /// The Target of the Request Line as received (`request.url`), with Query
+    /// & Fragment, e.g. for custom Routing or Logs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let c: Context = Context::test_request("get", "/caf%C3%A9?a=1", "").await;
+    /// assert_eq!(c.request_target().await, "/caf%C3%A9?a=1");
+    /// # }
+    /// ```
+    pub async fn request_target(&self) -> &str {
+        &self.request.url
+    }
+    /// Get Matched Route
+    ///
+    /// The registered path pattern of the Route the Request matched, e.g.
+    /// `/user/:id` for `/user/42` (with the prefix of a mounted Router). Set
+    /// before the Middlewares run, `None` if no Route matched (`404`, `405`,
+    /// automatic `OPTIONS`). Useful as a low-cardinality Metrics label.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route, middleware, tail};
+    ///
+    /// async fn metrics(mut c: Context) -> Returns {
+    ///     c.next = true;
+    ///
+    ///     tail! {
+    ///         c,
+    ///         {
+    ///             let route: String = c.matched_route().await.unwrap_or("unmatched").to_owned();
+    ///             println!("{} {} {}", c.request.method, route, c.response.status);
+    ///             c
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// async fn user(mut c: Context) -> Returns {
+    ///     c.response.body = format!("Route: {:?}", c.matched_route().await);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(metrics));
+    /// app.add(route!("get /user/:id", user));
+    /// ```
+    ///
+    /// Matched & Not Found
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route, middleware, tail};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn label(mut c: Context) -> Returns {
+    ///     c.next = true;
+    ///
+    ///     tail! {
+    ///         c,
+    ///         {
+    ///             c.response.body = format!("{:?}", c.matched_route().await);
+    ///             c
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// async fn user(c: Context) -> Returns {
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(middleware!(label));
+    /// app.add(route!("get /user/:id", user));
+    ///
+    /// for (request, label) in [("/user/42", "Some(\"/user/:id\")"), ("/missing", "None")] {
+    ///     let (mut client, stream) = duplex(1024);
+    ///
+    ///     client
+    ///         .write_all(format!("GET {} HTTP/1.1\r\n\r\n", request).as_bytes())
+    ///         .await
+    ///         .unwrap();
+    ///     client.shutdown().await.unwrap();
+    ///
+    ///     app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    ///     let mut response: String = String::new();
+    ///     client.read_to_string(&mut response).await.unwrap();
+    ///
+    ///     assert!(response.ends_with(label));
+    /// }
+    /// # }
+    /// ```
+    pub async fn matched_route(&self) -> Option<&str> {
+        self.matched_route.as_deref()
+    }
+    /// Get Response Size
+    ///
+    /// Number of Response Body bytes written to the connection, counted as
+    /// they are written (Files, Byte Ranges & streamed Chunks without their
+    /// framing included, Headers not). Routes & Tails run before the Response
+    /// is written, so it is complete on a Clone of the Context (clones share
+    /// the count) once the Response was sent. The access log uses it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, middleware, route};
+    /// use std::net::SocketAddr;
+    /// use std::sync::{Arc, Mutex};
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn index(mut c: Context) -> Returns {
+    ///     c.response.body = "Hello".to_owned();
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let sent: Arc<Mutex<Option<Context>>> = Arc::new(Mutex::new(None));
+    /// let keep: Arc<Mutex<Option<Context>>> = sent.clone();
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(move |mut c: Context| {
+    ///     c.next = true;
+    ///     *keep.lock().unwrap() = Some(c.clone());
+    ///     async move { (c, None) }
+    /// }));
+    /// app.add(route!("get /", index));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client.write_all(b"GET / HTTP/1.1\r\n\r\n").await.unwrap();
+    /// client.shutdown().await.unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// let c: Context = sent.lock().unwrap().take().unwrap();
+    /// assert_eq!(c.response_size().await, 5);
+    /// # }
+    /// ```
+    pub async fn response_size(&self) -> usize {
+        self.response_size.load(Ordering::Relaxed)
+    }
+    /// Set Response Status
+    ///
+    /// Takes a `StatusCode` or a `u16`, sets `response.status`. The Status
+    /// Line has the canonical Reason Phrase (`Unknown` for nonstandard Codes).
+    /// A Code outside of 100 to 999 can't be sent & falls back to `500`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Context, Returns};
+    /// use oxidy::structs::status_code::StatusCode;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.status(StatusCode::Created).await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let (c, _) = route(Context::test_request("post", "/", "").await).await;
+    /// assert_eq!(c.response.status, 201);
+    ///
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// c.status(418).await;
+    /// assert_eq!(c.response.status, 418);
+    ///
+    /// c.status(1000).await;
+    /// assert_eq!(c.response.status, 500);
+    /// # }
+    /// ```
+    pub async fn status<S: TryInto<StatusCode>>(&mut self, status: S) {
+        let status: StatusCode = status.try_into().unwrap_or(StatusCode::InternalServerError);
+
+        self.response.status = status.as_u16() as usize;
+    }
+    /// Respond Plain Text
+    ///
+    /// Sets `response.body` & `text/plain; charset=utf-8`, replacing an
+    /// earlier File, Stream or Bytes Body. Composes with `status`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Context, Returns};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.status(201).await;
+    ///     c.text("Created").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let (c, _) = route(Context::test_request("post", "/", "").await).await;
+    /// assert_eq!(c.response.status, 201);
+    /// assert_eq!(c.response.body, "Created");
+    /// assert_eq!(c.response.content_type, "text/plain; charset=utf-8");
+    /// # }
+    /// ```
+    pub async fn text(&mut self, body: impl Into<String>) {
+        self.response.body = body.into();
+        self.response.content_type = "text/plain; charset=utf-8".to_owned();
+        self.response.payload = None;
+    }
+    /// Respond HTML
+    ///
+    /// Sets `response.body` & `text/html; charset=utf-8`, replacing an earlier
+    /// File, Stream or Bytes Body. Composes with `status`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Context, Returns};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.html("<h1>Hello</h1>").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let (c, _) = route(Context::test_request("get", "/", "").await).await;
+    /// assert_eq!(c.response.body, "<h1>Hello</h1>");
+    /// assert_eq!(c.response.content_type, "text/html; charset=utf-8");
+    /// # }
+    /// ```
+    pub async fn html(&mut self, body: impl Into<String>) {
+        self.response.body = body.into();
+        self.response.content_type = "text/html; charset=utf-8".to_owned();
+        self.response.payload = None;
+    }
+    /// Respond Binary Data
+    ///
+    /// Writes the Bytes as the Body with `application/octet-stream`, set
+    /// `response.content_type` afterwards for another Type. `response.body` is
+    /// cleared. Composes with `status`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn pixel(mut c: Context) -> Returns {
+    ///     c.bytes(vec![0x47, 0x49, 0x46]).await;
+    ///     c.response.content_type = "image/gif".to_owned();
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("get /pixel.gif", pixel));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client.write_all(b"GET /pixel.gif HTTP/1.1\r\n\r\n").await.unwrap();
+    /// client.shutdown().await.unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.contains("Content-Type: image/gif"));
+    /// assert!(response.contains("Content-Length: 3"));
+    /// assert!(response.ends_with("GIF"));
+    /// # }
+    /// ```
+    pub async fn bytes(&mut self, body: Vec<u8>) {
+        self.response.body = String::new();
+        self.response.content_type = "application/octet-stream".to_owned();
+        self.response.payload = Some(Payload::Bytes(body));
+    }
+    /// Get Application State
+    ///
+    /// The State registered with `Server::with_state`, looked up by its Type.
+    /// Panics (the Request responds `500`) if no State of the Type is
+    /// registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// struct Config {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let name: String = c.state::<Config>().await.name.clone();
+    ///     c.response.body = format!("App: {}", name);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.with_state(Config {
+    ///     name: "oxidy".to_owned(),
+    /// });
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn state<T: Send + Sync + 'static>(&self) -> &T {
+        self.states
+            .get::<T>()
+            .unwrap_or_else(|| panic!("[Error] State not registered: {}", type_name::<T>()))
+    }
+    /// Get Configuration
+    ///
+    /// Snapshot of the Configuration registered with `Server::with_config`,
+    /// taken on the first call & the same for the rest of the Request (also in
+    /// Tails), even if `Server::reload_config` swaps it meanwhile. Panics (the
+    /// Request responds `500`) if no Configuration of the Type is registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::sync::Arc;
+    ///
+    /// struct Limits {
+    ///     page_size: usize,
+    /// }
+    ///
+    /// async fn list(mut c: Context) -> Returns {
+    ///     let limits: Arc<Limits> = c.config::<Limits>().await;
+    ///     c.response.body = format!("Page Size: {}", limits.page_size);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.with_config(Limits { page_size: 50 });
+    /// app.add(route!("get /items", list));
+    /// ```
+    pub async fn config<T: Send + Sync + 'static>(&mut self) -> Arc<T> {
+        if let Some(x) = self.extensions.get::<ConfigSnapshot<T>>() {
+            return x.0.clone();
+        }
+
+        let x: Arc<T> = self
+            .states
+            .get::<ConfigCell<T>>()
+            .unwrap_or_else(|| panic!("[Error] Config not registered: {}", type_name::<T>()))
+            .0
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+
+        self.extensions.insert(ConfigSnapshot(x.clone()));
+
+        x
+    }
+    /// Set Signed Cookie
+    ///
+    /// Appends an HMAC-SHA256 signature made with `Server::secret_key` to the
+    /// Cookie Value, so the Client can read but not change it. Read it back
+    /// with `signed_cookie`. Panics (the Request responds `500`) if no Secret
+    /// Key is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::cookie::Cookie;
+    ///
+    /// async fn login(mut c: Context) -> Returns {
+    ///     c.set_signed_cookie(Cookie::new("user", "42").path("/").http_only(true))
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.secret_key(b"a-long-random-secret-of-32-bytes-or-more");
+    /// app.add(route!("post /login", login));
+    /// ```
+    ///
+    /// The signature is the hex HMAC-SHA256 of `name=value`:
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::cookie::Cookie;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn login(mut c: Context) -> Returns {
+    ///     c.set_signed_cookie(Cookie::new("user", "42")).await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.secret_key(b"a-long-random-secret-of-32-bytes-or-more");
+    /// app.add(route!("post /login", login));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client.write_all(b"POST /login HTTP/1.1\r\n\r\n").await.unwrap();
+    /// client.shutdown().await.unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.contains(
+    ///     "Set-Cookie: user=42.9c8463dee0a20d57621a36dbdd220472a5a304db19992d4a1255ddb71882a8fe\r\n"
+    /// ));
+    /// # }
+    /// ```
+    pub async fn set_signed_cookie(&mut self, cookie: Cookie) {
+        let cookie: Cookie = cookie.signed(self.secret_key());
+
+        self.response.set_cookie(cookie).await;
+    }
+    /// Get Signed Cookie
+    ///
+    /// The Value of a Cookie set with `set_signed_cookie`, `None` if missing
+    /// or its signature does not verify (tampered or signed with another
+    /// Key). Panics (the Request responds `500`) if no Secret Key is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::cookie::Cookie;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn login(mut c: Context) -> Returns {
+    ///     c.set_signed_cookie(Cookie::new("user", "42")).await;
+    ///     (c, None)
+    /// }
+    ///
+    /// async fn me(mut c: Context) -> Returns {
+    ///     c.response.body = c.signed_cookie("user").await.unwrap_or("anonymous".to_owned());
+    ///     (c, None)
+    /// }
+    ///
+    /// async fn request(app: &Server, request: String) -> String {
+    ///     let (mut client, stream) = duplex(4096);
+    ///
+    ///     client.write_all(request.as_bytes()).await.unwrap();
+    ///     client.shutdown().await.unwrap();
+    ///
+    ///     app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    ///     let mut response: String = String::new();
+    ///     client.read_to_string(&mut response).await.unwrap();
+    ///     response
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.secret_key(b"a-long-random-secret-of-32-bytes-or-more");
+    /// app.add(route!("post /login", login));
+    /// app.add(route!("get /me", me));
+    ///
+    /// let response: String = request(&app, "POST /login HTTP/1.1\r\n\r\n".to_owned()).await;
+    ///
+    /// let cookie: &str = response
+    ///     .split("Set-Cookie: ")
+    ///     .nth(1)
+    ///     .and_then(|x: &str| x.split("\r\n").next())
+    ///     .unwrap();
+    ///
+    /// let response: String =
+    ///     request(&app, format!("GET /me HTTP/1.1\r\nCookie: {}\r\n\r\n", cookie)).await;
+    ///
+    /// assert!(response.ends_with("42"));
+    ///
+    /// /* Value changed from 42 to 1 */
+    /// let tampered: String = cookie.replacen("user=42.", "user=1.", 1);
+    /// let response: String =
+    ///     request(&app, format!("GET /me HTTP/1.1\r\nCookie: {}\r\n\r\n", tampered)).await;
+    ///
+    /// assert!(response.ends_with("anonymous"));
+    /// # }
+    /// ```
+    pub async fn signed_cookie(&mut self, name: &str) -> Option<String> {
+        let signed: String = self.request.cookie(name).await?;
+
+        self.secret_key().verify(name, &signed)
+    }
+    /// Render an HTML Template
+    ///
+    /// Requires the `templates` feature. Renders the Template loaded with
+    /// `Server::templates` with the Data as the Response Body (`text/html`).
+    /// A failing Render (e.g. unknown Template) sets status `500` with a plain
+    /// text body, so a `Server::catch_status` for `500` handles it, & returns
+    /// the Error. Without loaded Templates every Template is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use serde::Serialize;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     name: String,
+    /// }
+    ///
+    /// async fn user(mut c: Context) -> Returns {
+    ///     let user: User = User {
+    ///         name: c.request.param("name").await,
+    ///     };
+    ///
+    ///     c.render("user", &user).await.ok();
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let dir = std::env::temp_dir().join(format!("oxidy-templates-{}", std::process::id()));
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("user.hbs"), "<h1>Hello {{name}}</h1>").unwrap();
+    ///
+    /// let mut app = Server::new();
+    /// app.templates(&dir.display().to_string()).unwrap();
+    /// app.add(route!("get /user/:name", user));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client.write_all(b"GET /user/<Ada> HTTP/1.1\r\n\r\n").await.unwrap();
+    /// client.shutdown().await.unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    /// std::fs::remove_dir_all(&dir).unwrap();
+    ///
+    /// assert!(response.contains("Content-Type: text/html; charset=utf-8"));
+    /// assert!(response.ends_with("<h1>Hello &lt;Ada&gt;</h1>"));
+    ///
+    /// /* Without Templates */
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// assert!(c.render("user", &()).await.is_err());
+    /// assert_eq!(c.response.status, 500);
+    /// # }
+    /// ```
+    #[cfg(feature = "templates")]
+    pub async fn render<T: serde::Serialize>(
+        &mut self,
+        name: &str,
+        data: &T,
+    ) -> Result<(), RenderError> {
+        let rendered: Result<String, RenderError> = match self.states.get::<Templates>() {
+            Some(x) => x.0.render(name, data),
+            None => Err(RenderErrorReason::TemplateNotFound(name.to_owned()).into()),
+        };
+
+        match rendered {
+            Ok(x) => {
+                self.response.body = x;
+                self.response.content_type = "text/html; charset=utf-8".to_owned();
+                Ok(())
+            }
+            Err(e) => {
+                self.response.status = 500;
+                self.response.body = "Internal Server Error".to_owned();
+                self.response.content_type = "text/plain".to_owned();
+                Err(e)
+            }
+        }
+    }
+    /*
+     * Secret Key of `Server::secret_key`
+     */
+    fn secret_key(&self) -> &SecretKey {
+        self.states
+            .get::<SecretKey>()
+            .unwrap_or_else(|| panic!("[Error] Secret Key not set"))
+    }
+    /// Get Session
+    ///
+    /// The Session loaded by the Session Middleware
+    /// (`middlewares::session::Sessions`). None if the Middleware did not run
+    /// before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route, middleware};
+    /// use oxidy::middlewares::session::Sessions;
+    /// use oxidy::structs::session::Session;
+    ///
+    /// async fn visits(mut c: Context) -> Returns {
+    ///     let session: Session = match c.session().await {
+    ///         Some(x) => x,
+    ///         None => return (c, None),
+    ///     };
+    ///     let count: u64 = session
+    ///         .get("visits")
+    ///         .await
+    ///         .and_then(|x: String| x.parse().ok())
+    ///         .unwrap_or(0)
+    ///         + 1;
+    ///
+    ///     session.set("visits", &count.to_string()).await;
+    ///     c.response.body = format!("Visits: {}", count);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(Sessions::new().middleware()));
+    /// app.add(route!("get /", visits));
+    /// ```
+    pub async fn session(&self) -> Option<Session> {
+        self.extensions.get::<Session>().cloned()
+    }
+    /// Get Request ID
+    ///
+    /// The ID set by the Request ID Middleware
+    /// (`middlewares::request_id::RequestIds`), the same for the whole
+    /// Request. None if the Middleware did not run before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, middleware, tail};
+    /// use oxidy::middlewares::request_id::RequestIds;
+    ///
+    /// async fn log(mut c: Context) -> Returns {
+    ///     c.next = true;
+    ///
+    ///     tail! {
+    ///         c,
+    ///         {
+    ///             let id: String = c.request_id().await.unwrap_or_default();
+    ///             println!("[{}] {}", id, c.response.status);
+    ///             c
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(RequestIds::new().middleware()));
+    /// app.add(middleware!(log));
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// /* Without the Middleware */
+    /// let c: Context = Context::test_request("get", "/", "").await;
+    /// assert_eq!(c.request_id().await, None);
+    /// # });
+    /// ```
+    pub async fn request_id(&self) -> Option<String> {
+        self.extensions
+            .get::<RequestId>()
+            .map(|x: &RequestId| x.0.clone())
+    }
+    /// Get CSRF Token
+    ///
+    /// The Token of the Session kept by the CSRF Middleware
+    /// (`middlewares::csrf::Csrf`), to embed in Forms. None if the Middleware
+    /// did not run before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route, middleware};
+    /// use oxidy::middlewares::csrf::Csrf;
+    /// use oxidy::middlewares::session::Sessions;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     if let Some(token) = c.csrf_token().await {
+    ///         c.response.set_header("X-CSRF-Token", &token).await;
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(middleware!(Sessions::new().middleware()));
+    /// app.add(middleware!(Csrf::new().middleware()));
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn csrf_token(&self) -> Option<String> {
+        self.extensions
+            .get::<CsrfToken>()
+            .map(|x: &CsrfToken| x.0.clone())
+    }
+    /// Upgrade to a WebSocket
+    ///
+    /// Requires the `websocket` feature. Checks the Handshake (`GET` with
+    /// `Upgrade: websocket`, `Sec-WebSocket-Key` & `Sec-WebSocket-Version: 13`)
+    /// & sets the `101 Switching Protocols` Response. After it is written the
+    /// Function runs with the `WebSocket` until it returns & the connection
+    /// closes. Returns `false` without changing the Response if the Request is
+    /// no valid Handshake. Messages are limited to `Server::max_body_size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::websocket::{Message, WebSocket};
+    ///
+    /// async fn echo(mut c: Context) -> Returns {
+    ///     let upgraded: bool = c
+    ///         .websocket(|mut ws: WebSocket| async move {
+    ///             while let Some(Message::Text(x)) = ws.recv().await {
+    ///                 ws.send(Message::Text(x.to_uppercase())).await.ok();
+    ///             }
+    ///         })
+    ///         .await;
+    ///
+    ///     if !upgraded {
+    ///         c.response.status = 400;
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /ws", echo));
+    /// ```
+    #[cfg(feature = "websocket")]
+    pub async fn websocket<F, Fut>(&mut self, func: F) -> bool
+    where
+        F: FnOnce(WebSocket) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let has_token = |value: Option<String>, token: &str| -> bool {
+            value.is_some_and(|x: String| {
+                x.split(',')
+                    .any(|x: &str| x.trim().eq_ignore_ascii_case(token))
+            })
+        };
+
+        let key: Option<String> = self.request.header("sec-websocket-key").await;
+
+        let valid: bool = self.request.method.eq_ignore_ascii_case("GET")
+            && self.request.http_version >= 1.1
+            && has_token(self.request.header("upgrade").await, "websocket")
+            && has_token(self.request.header("connection").await, "upgrade")
+            && self
+                .request
+                .header("sec-websocket-version")
+                .await
+                .as_deref()
+                == Some("13")
+            && key
+                .as_deref()
+                .and_then(|x: &str| STANDARD.decode(x.trim()).ok())
+                .is_some_and(|x: Vec<u8>| x.len() == 16);
+
+        let key: String = match key {
+            Some(x) if valid => x,
+            _ => return false,
+        };
+        /*
+         * Accept Key: Base64 SHA-1 of the Key & the WebSocket GUID
+         */
+        let accept: String = STANDARD.encode(Sha1::digest(
+            format!("{}258EAFA5-E914-47DA-95CA-C5AB0DC85B11", key.trim()).as_bytes(),
+        ));
+
+        self.response.status = 101;
+        self.response.body = String::new();
+        self.response.set_header("Upgrade", "websocket").await;
+        self.response.set_header("Connection", "Upgrade").await;
+        self.response
+            .set_header("Sec-WebSocket-Accept", &accept)
+            .await;
+        self.response.payload = Some(Payload::WebSocket(Upgrade(Arc::new(Mutex::new(Some(
+            Box::new(move |ws: WebSocket| -> BoxFuture<'static, ()> { Box::pin(func(ws)) }),
+        ))))));
+
+        true
+    }
 }
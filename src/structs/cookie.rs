@@ -0,0 +1,193 @@
+use crate::utils::constant_time_eq::constant_time_eq;
+use crate::utils::hmac_sha256::hmac_sha256;
+use crate::utils::http_date::http_date;
+use std::fmt::{Display, Formatter, Result};
+use std::time::SystemTime;
+
+/*
+ * Key signing Cookies, registered with `Server::secret_key`
+ */
+pub(crate) struct SecretKey(pub(crate) Vec<u8>);
+
+impl SecretKey {
+    /*
+     * Hex HMAC-SHA256 of `name=value`, binding the Value to the Cookie Name
+     */
+    fn signature(&self, name: &str, value: &str) -> String {
+        hmac_sha256(&self.0, format!("{}={}", name, value).as_bytes())
+            .iter()
+            .map(|x: &u8| format!("{:02x}", x))
+            .collect()
+    }
+    /*
+     * Value of a signed Cookie (`value.signature`), None if tampered
+     */
+    pub(crate) fn verify(&self, name: &str, signed: &str) -> Option<String> {
+        let (value, signature) = signed.rsplit_once('.')?;
+
+        constant_time_eq(signature.as_bytes(), self.signature(name, value).as_bytes())
+            .then(|| value.to_owned())
+    }
+}
+
+/// Cookie `SameSite` Attribute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+/// Response Cookie (`Set-Cookie`)
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::cookie::{Cookie, SameSite};
+///
+/// async fn route(mut c: Context) -> Returns {
+///     let cookie: Cookie = Cookie::new("session", "abcd1234")
+///         .path("/")
+///         .http_only(true)
+///         .secure(true)
+///         .same_site(SameSite::Lax)
+///         .max_age(3600);
+///
+///     assert_eq!(
+///         cookie.to_string(),
+///         "session=abcd1234; Path=/; Max-Age=3600; HttpOnly; Secure; SameSite=Lax"
+///     );
+///
+///     c.response.set_cookie(cookie).await;
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("get /", route));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    domain: Option<String>,
+    max_age: Option<i64>,
+    expires: Option<SystemTime>,
+    http_only: bool,
+    secure: bool,
+    same_site: Option<SameSite>,
+}
+
+impl Cookie {
+    /// New Cookie with Name & Value
+    pub fn new(name: &str, value: &str) -> Cookie {
+        Cookie {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            path: None,
+            domain: None,
+            max_age: None,
+            expires: None,
+            http_only: false,
+            secure: false,
+            same_site: None,
+        }
+    }
+    /// Cookie Name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Cookie Value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+    /// Set `Path` Attribute
+    pub fn path(mut self, path: &str) -> Cookie {
+        self.path = Some(path.to_owned());
+        self
+    }
+    /// Set `Domain` Attribute
+    pub fn domain(mut self, domain: &str) -> Cookie {
+        self.domain = Some(domain.to_owned());
+        self
+    }
+    /// Set `Max-Age` Attribute in Seconds
+    pub fn max_age(mut self, seconds: i64) -> Cookie {
+        self.max_age = Some(seconds);
+        self
+    }
+    /// Set `Expires` Attribute
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::cookie::Cookie;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let cookie: Cookie =
+    ///     Cookie::new("theme", "dark").expires(UNIX_EPOCH + Duration::from_secs(784111777));
+    ///
+    /// assert_eq!(
+    ///     cookie.to_string(),
+    ///     "theme=dark; Expires=Sun, 06 Nov 1994 08:49:37 GMT"
+    /// );
+    /// ```
+    pub fn expires(mut self, time: SystemTime) -> Cookie {
+        self.expires = Some(time);
+        self
+    }
+    /// Set `HttpOnly` Attribute
+    pub fn http_only(mut self, http_only: bool) -> Cookie {
+        self.http_only = http_only;
+        self
+    }
+    /// Set `Secure` Attribute
+    pub fn secure(mut self, secure: bool) -> Cookie {
+        self.secure = secure;
+        self
+    }
+    /// Set `SameSite` Attribute
+    pub fn same_site(mut self, same_site: SameSite) -> Cookie {
+        self.same_site = Some(same_site);
+        self
+    }
+    /*
+     * Append the Signature to the Value
+     */
+    pub(crate) fn signed(mut self, key: &SecretKey) -> Cookie {
+        self.value = format!("{}.{}", self.value, key.signature(&self.name, &self.value));
+        self
+    }
+}
+
+impl Display for Cookie {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}={}", self.name, self.value)?;
+
+        if let Some(x) = &self.path {
+            write!(f, "; Path={}", x)?;
+        }
+        if let Some(x) = &self.domain {
+            write!(f, "; Domain={}", x)?;
+        }
+        if let Some(x) = self.max_age {
+            write!(f, "; Max-Age={}", x)?;
+        }
+        if let Some(x) = self.expires {
+            write!(f, "; Expires={}", http_date(x))?;
+        }
+        if self.http_only {
+            write!(f, "; HttpOnly")?;
+        }
+        if self.secure {
+            write!(f, "; Secure")?;
+        }
+        match self.same_site {
+            Some(SameSite::Strict) => write!(f, "; SameSite=Strict"),
+            Some(SameSite::Lax) => write!(f, "; SameSite=Lax"),
+            Some(SameSite::None) => write!(f, "; SameSite=None"),
+            None => Ok(()),
+        }
+    }
+}
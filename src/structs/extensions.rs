@@ -0,0 +1,33 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+
+/*
+ * Typed Values, one per Type (Request Extensions & Application State)
+ * Clones share the Values
+ */
+#[derive(Clone, Default)]
+pub(crate) struct Extensions(HashMap<TypeId, Arc<dyn Any + Send + Sync>>);
+
+impl Extensions {
+    pub(crate) fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.0.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    pub(crate) fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.0
+            .get(&TypeId::of::<T>())
+            .and_then(|x: &Arc<dyn Any + Send + Sync>| x.downcast_ref::<T>())
+    }
+
+    pub(crate) fn remove<T: Send + Sync + 'static>(&mut self) {
+        self.0.remove(&TypeId::of::<T>());
+    }
+}
+
+impl Debug for Extensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Extensions({})", self.0.len())
+    }
+}
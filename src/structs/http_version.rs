@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// HTTP Version of a Request
+///
+/// Request Lines with an unknown Version are served as `Http09`, so are
+/// unknown `Request::http_version` values converted with `From<f64>`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::http_version::HttpVersion;
+///
+/// assert_eq!(HttpVersion::Http11.to_string(), "HTTP/1.1");
+/// assert!(HttpVersion::Http11 > HttpVersion::Http10);
+///
+/// assert_eq!(HttpVersion::from(2.0), HttpVersion::Http20);
+/// assert_eq!(HttpVersion::from(4.0), HttpVersion::Http09);
+/// assert_eq!(HttpVersion::from(1.5), HttpVersion::Http09);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpVersion {
+    Http09,
+    Http10,
+    Http11,
+    Http20,
+    Http30,
+}
+
+impl HttpVersion {
+    /// Version as in the Request Line
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::http_version::HttpVersion;
+    ///
+    /// assert_eq!(HttpVersion::Http10.as_str(), "HTTP/1.0");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpVersion::Http09 => "HTTP/0.9",
+            HttpVersion::Http10 => "HTTP/1.0",
+            HttpVersion::Http11 => "HTTP/1.1",
+            HttpVersion::Http20 => "HTTP/2.0",
+            HttpVersion::Http30 => "HTTP/3.0",
+        }
+    }
+}
+
+impl From<f64> for HttpVersion {
+    /*
+     * From `Request::http_version`
+     */
+    fn from(version: f64) -> HttpVersion {
+        if version == 3.0 {
+            HttpVersion::Http30
+        } else if version == 2.0 {
+            HttpVersion::Http20
+        } else if version == 1.1 {
+            HttpVersion::Http11
+        } else if version == 1.0 {
+            HttpVersion::Http10
+        } else {
+            HttpVersion::Http09
+        }
+    }
+}
+
+impl Display for HttpVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
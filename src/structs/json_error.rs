@@ -0,0 +1,68 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// JSON Request Body Error
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::json_error::JsonError;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// async fn route(mut c: Context) -> Returns {
+///     let user: Result<User, JsonError> = c.request.json().await;
+///     match user {
+///         Ok(u) => c.response.body = format!("Username: {}", u.name),
+///         Err(e) => {
+///             c.response.body = e.to_string();
+///             c.response.status = 400;
+///         }
+///     }
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("post /user", route));
+/// ```
+#[derive(Debug)]
+pub enum JsonError {
+    /// Request Body is Empty
+    Empty,
+    /// Request Content Type is not `application/json`
+    ContentType(String),
+    /// Request Body is not valid JSON
+    Syntax(serde_json::Error),
+    /// Request Body does not match the target type
+    Data(serde_json::Error),
+    /// Streamed Request Body was consumed by `body_reader` or `multipart_upload`
+    Consumed,
+}
+
+impl Display for JsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            JsonError::Empty => write!(f, "Request body is empty"),
+            JsonError::ContentType(x) => {
+                write!(f, "Expected content type application/json, found: {}", x)
+            }
+            JsonError::Syntax(e) => write!(f, "Request body is not valid JSON: {}", e),
+            JsonError::Data(e) => write!(f, "Request body does not match: {}", e),
+            JsonError::Consumed => write!(f, "Request body already consumed"),
+        }
+    }
+}
+
+impl Error for JsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            JsonError::Syntax(e) | JsonError::Data(e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use tokio::io::Error;
+use tokio::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+
+/*
+ * Listener accepted by the Connection Loop (TCP or Unix Socket)
+ */
+pub(crate) trait Listener {
+    type Stream: Send + 'static;
+
+    fn accept(&self) -> impl Future<Output = Result<(Self::Stream, SocketAddr), Error>> + Send;
+    /*
+     * Address shown in the Listening Log
+     */
+    fn name(&self) -> Result<String, Error>;
+    /*
+     * Apply Socket Options to an accepted Stream
+     */
+    fn configure(stream: &Self::Stream, nodelay: bool);
+}
+
+impl Listener for TcpListener {
+    type Stream = TcpStream;
+
+    async fn accept(&self) -> Result<(TcpStream, SocketAddr), Error> {
+        TcpListener::accept(self).await
+    }
+
+    fn name(&self) -> Result<String, Error> {
+        Ok(self.local_addr()?.to_string())
+    }
+
+    fn configure(stream: &TcpStream, nodelay: bool) {
+        /*
+         * TCP_NODELAY, the Connection is served even if it fails
+         */
+        stream.set_nodelay(nodelay).ok();
+    }
+}
+/*
+ * Unix Socket Peers have no IP Address, `0.0.0.0:0` stands in
+ */
+#[cfg(unix)]
+impl Listener for UnixListener {
+    type Stream = UnixStream;
+
+    async fn accept(&self) -> Result<(UnixStream, SocketAddr), Error> {
+        let (stream, _) = UnixListener::accept(self).await?;
+
+        Ok((stream, SocketAddr::from(([0, 0, 0, 0], 0))))
+    }
+
+    fn name(&self) -> Result<String, Error> {
+        Ok(match self.local_addr()?.as_pathname() {
+            Some(x) => format!("unix:{}", x.display()),
+            None => "unix".to_owned(),
+        })
+    }
+
+    fn configure(_: &UnixStream, _: bool) {}
+}
@@ -0,0 +1,101 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// HTTP Method
+///
+/// Methods without a Variant (e.g. WebDAV `PROPFIND`) are `Other` in Upper
+/// Case.
+///
+/// Routable Methods are `GET`, `HEAD`, `POST`, `PUT`, `PATCH`, `DELETE` &
+/// `OPTIONS`. Requests with `CONNECT` or `TRACE` respond `501 Not
+/// Implemented`, other Methods `400 Bad Request`, unless a Route is
+/// registered for that Method.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::method::Method;
+///
+/// assert_eq!(Method::Patch.to_string(), "PATCH");
+/// assert_eq!(Method::from("get"), Method::Get);
+/// assert_eq!(Method::from("propfind"), Method::Other("PROPFIND".to_owned()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+    Connect,
+    Trace,
+    Other(String),
+}
+
+impl Method {
+    /// Method Name in Upper Case
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::method::Method;
+    ///
+    /// assert_eq!(Method::Get.as_str(), "GET");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+            Method::Options => "OPTIONS",
+            Method::Connect => "CONNECT",
+            Method::Trace => "TRACE",
+            Method::Other(x) => x,
+        }
+    }
+    /// Whether Requests with this Method are routed without a Route
+    /// registered for it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::method::Method;
+    ///
+    /// assert!(Method::Patch.is_routable());
+    /// assert!(!Method::Trace.is_routable());
+    /// assert!(!Method::from("propfind").is_routable());
+    /// ```
+    pub fn is_routable(&self) -> bool {
+        !matches!(self, Method::Connect | Method::Trace | Method::Other(_))
+    }
+}
+
+impl From<&str> for Method {
+    /*
+     * Case-Insensitive like Route Methods
+     */
+    fn from(method: &str) -> Method {
+        match method.to_uppercase().as_str() {
+            "GET" => Method::Get,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "PATCH" => Method::Patch,
+            "DELETE" => Method::Delete,
+            "HEAD" => Method::Head,
+            "OPTIONS" => Method::Options,
+            "CONNECT" => Method::Connect,
+            "TRACE" => Method::Trace,
+            x => Method::Other(x.to_owned()),
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -1,4 +1,33 @@
+pub(crate) mod access_log;
+pub mod body_error;
+pub mod body_reader;
+pub mod chunk_writer;
+pub(crate) mod config;
 pub mod context;
+pub mod cookie;
 pub mod definition;
+pub(crate) mod extensions;
+pub mod http_version;
+#[cfg(feature = "json")]
+pub mod json_error;
+pub(crate) mod listener;
+pub mod method;
+pub mod multipart;
+pub mod multipart_error;
+pub(crate) mod payload;
+pub mod pool;
 pub mod request;
 pub mod response;
+pub mod server_error;
+pub mod session;
+pub mod shutdown;
+pub mod sse;
+pub mod status_code;
+pub mod temp_file;
+#[cfg(feature = "templates")]
+pub(crate) mod templates;
+pub(crate) mod timeout_writer;
+pub(crate) mod tree;
+pub mod uploads;
+#[cfg(feature = "websocket")]
+pub mod websocket;
@@ -0,0 +1,130 @@
+use crate::structs::temp_file::TempFile;
+use crate::utils::get_vec::get_vec;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::fs::{copy, write};
+use tokio::io::Error;
+
+/// Multipart Request Body (`multipart/form-data`)
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::multipart::{Multipart, Part};
+///
+/// async fn upload(mut c: Context) -> Returns {
+///     let multipart: Multipart = match c.request.multipart().await {
+///         Ok(x) => x,
+///         Err(e) => {
+///             c.response.status = 400;
+///             c.response.body = e.to_string();
+///             return (c, None);
+///         }
+///     };
+///
+///     for part in multipart.parts() {
+///         match part.filename() {
+///             Some(filename) => println!("File {}: {} bytes", filename, part.data().len()),
+///             None => println!("Field {}: {}", part.name(), part.text()),
+///         }
+///     }
+///
+///     if let Some(avatar) = multipart.part("avatar") {
+///         if avatar.save("/tmp/avatar.png").await.is_err() {
+///             c.response.status = 500;
+///         }
+///     }
+///
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("post /upload", upload));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Multipart {
+    pub(crate) parts: Vec<Part>,
+}
+
+/// Part of a Multipart Request Body (Field or File)
+///
+/// File Parts of `Request::multipart_upload` are on Disk (`temp_file`) with
+/// an empty `data`.
+#[derive(Clone, Debug, Default)]
+pub struct Part {
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) name: String,
+    pub(crate) filename: Option<String>,
+    pub(crate) content_type: Option<String>,
+    pub(crate) data: Vec<u8>,
+    pub(crate) file: Option<Arc<TempFile>>,
+}
+
+impl Multipart {
+    /// All Parts in Request Order
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+    /// First Part with the Field Name
+    pub fn part(&self, name: &str) -> Option<&Part> {
+        self.parts.iter().find(|x: &&Part| x.name == name)
+    }
+    /// Parts with a Filename (File Uploads)
+    pub fn files(&self) -> Vec<&Part> {
+        self.parts
+            .iter()
+            .filter(|x: &&Part| x.filename.is_some())
+            .collect()
+    }
+}
+
+impl IntoIterator for Multipart {
+    type Item = Part;
+    type IntoIter = std::vec::IntoIter<Part>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter()
+    }
+}
+
+impl Part {
+    /// Field Name from `Content-Disposition`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Filename from `Content-Disposition` (File Uploads)
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+    /// Part Content Type
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+    /// Part Header (case-insensitive)
+    pub async fn header(&self, key: &str) -> Option<String> {
+        get_vec(&self.headers, key.to_owned()).await
+    }
+    /// Part Content
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+    /// Part Content as Text (lossy UTF-8)
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.data).to_string()
+    }
+    /// Temporary File of the Content (`Request::multipart_upload`)
+    pub fn temp_file(&self) -> Option<&TempFile> {
+        self.file.as_deref()
+    }
+    /// Write Part Content to a File
+    ///
+    /// Copies the Temporary File of uploaded Parts, see `TempFile::persist`
+    /// to move it instead.
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        match &self.file {
+            Some(x) => copy(x.path(), path).await.map(|_| ()),
+            None => write(path, &self.data).await,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+/// Multipart Request Body Error
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::multipart::Multipart;
+/// use oxidy::structs::multipart_error::MultipartError;
+///
+/// async fn route(mut c: Context) -> Returns {
+///     let multipart: Result<Multipart, MultipartError> = c.request.multipart().await;
+///     match multipart {
+///         Ok(m) => c.response.body = format!("{} parts", m.parts().len()),
+///         Err(e) => {
+///             c.response.body = e.to_string();
+///             c.response.status = 400;
+///         }
+///     }
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("post /upload", route));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum MultipartError {
+    /// Request Content Type is not `multipart/form-data`
+    ContentType(String),
+    /// Content Type has no `boundary` Parameter
+    Boundary,
+    /// Request Body is not valid Multipart
+    Malformed(String),
+    /// Part exceeds the Size Limit of `Uploads` (Field Name)
+    TooLarge(String),
+    /// Fail to read the Request Body or write a Temporary File
+    Io(String),
+    /// Streamed Request Body was consumed by `body_reader` or `multipart_upload`
+    Consumed,
+}
+
+impl Display for MultipartError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            MultipartError::ContentType(x) => {
+                write!(f, "Expected content type multipart/form-data, found: {}", x)
+            }
+            MultipartError::Boundary => write!(f, "Multipart boundary is missing"),
+            MultipartError::Malformed(x) => write!(f, "Request body is not valid multipart: {}", x),
+            MultipartError::TooLarge(x) => write!(f, "Multipart part is too large: {}", x),
+            MultipartError::Io(x) => write!(f, "Fail to read multipart body: {}", x),
+            MultipartError::Consumed => write!(f, "Request body already consumed"),
+        }
+    }
+}
+
+impl Error for MultipartError {}
@@ -0,0 +1,72 @@
+use crate::structs::chunk_writer::ChunkWriter;
+#[cfg(feature = "websocket")]
+use crate::structs::websocket::WebSocketFunc;
+use futures::future::BoxFuture;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::io::Error;
+
+/*
+ * Response Body written from a Source other than `Response::body`
+ */
+#[derive(Clone, Debug)]
+pub(crate) enum Payload {
+    /*
+     * File streamed from Disk
+     */
+    File(PathBuf),
+    /*
+     * Binary Body (e.g. compressed)
+     */
+    Bytes(Vec<u8>),
+    /*
+     * Chunks produced while writing the Response
+     */
+    Stream(Stream),
+    /*
+     * WebSocket Function run on the Connection after the 101 Response
+     */
+    #[cfg(feature = "websocket")]
+    WebSocket(Upgrade),
+}
+
+pub(crate) type StreamFunc =
+    Box<dyn FnOnce(ChunkWriter) -> BoxFuture<'static, Result<(), Error>> + Send>;
+/*
+ * Stream Producer, run once by the first Clone taking it
+ */
+#[derive(Clone)]
+pub(crate) struct Stream(pub(crate) Arc<Mutex<Option<StreamFunc>>>);
+
+impl Stream {
+    pub(crate) fn take(&self) -> Option<StreamFunc> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+impl Debug for Stream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Stream")
+    }
+}
+/*
+ * WebSocket Function, run once by the first Clone taking it
+ */
+#[cfg(feature = "websocket")]
+#[derive(Clone)]
+pub(crate) struct Upgrade(pub(crate) Arc<Mutex<Option<WebSocketFunc>>>);
+
+#[cfg(feature = "websocket")]
+impl Upgrade {
+    pub(crate) fn take(&self) -> Option<WebSocketFunc> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).take()
+    }
+}
+
+#[cfg(feature = "websocket")]
+impl Debug for Upgrade {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Upgrade")
+    }
+}
@@ -0,0 +1,319 @@
+use crate::utils::cpus::cpus;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::pin;
+use tokio::sync::Notify;
+
+/// Backpressure Strategy when the Pool Queue is full
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::pool::{Backpressure, Pool};
+///
+/// let pool: Pool = Pool::new(4);
+/// pool.max_queue(100);
+/// pool.backpressure(Backpressure::Reject);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Stop accepting new connections until the Queue has room (Default)
+    Block,
+    /// Respond `503 Service Unavailable` & close the connection
+    Reject,
+}
+
+/// Worker Pool
+///
+/// Limits how many Requests run their Route Functions concurrently. Requests
+/// beyond the size wait for a free worker. Clones share the same Pool, so a
+/// handle from `Server::pool` resizes the running Server.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::pool::Pool;
+///
+/// let pool: Pool = Pool::new(4);
+/// assert_eq!(pool.size(), 4);
+///
+/// pool.resize(8);
+/// assert_eq!(pool.size(), 8);
+/// assert_eq!(pool.active(), 0);
+/// ```
+#[derive(Clone)]
+pub struct Pool {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    state: Mutex<State>,
+    notify: Notify,
+    queue_notify: Notify,
+}
+
+struct State {
+    size: usize,
+    active: usize,
+    queued: usize,
+    max_queue: usize,
+    backpressure: Backpressure,
+}
+
+/*
+ * Worker Slot, released on Drop
+ */
+pub(crate) struct PoolGuard {
+    pool: Pool,
+}
+/*
+ * Queued Request, removed from the Queue on Drop (also when cancelled)
+ */
+struct QueueGuard<'a> {
+    pool: &'a Pool,
+}
+
+impl Default for Pool {
+    /*
+     * Unlimited Pool
+     */
+    fn default() -> Pool {
+        Pool::new(usize::MAX)
+    }
+}
+
+impl Pool {
+    /// New Pool with a number of Workers
+    ///
+    /// `0` means the number of CPU Cores, a Pool never has zero Workers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Server;
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(16);
+    /// assert_eq!(pool.size(), 16);
+    ///
+    /// let pool: Pool = Pool::new(0);
+    /// assert_eq!(pool.size(), Server::recommended_threads());
+    /// ```
+    pub fn new(size: usize) -> Pool {
+        Pool {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State {
+                    size: if size == 0 { cpus() } else { size },
+                    active: 0,
+                    queued: 0,
+                    max_queue: usize::MAX,
+                    backpressure: Backpressure::Block,
+                }),
+                notify: Notify::new(),
+                queue_notify: Notify::new(),
+            }),
+        }
+    }
+    /// Resize the Pool at Runtime
+    ///
+    /// Growing lets waiting Requests run immediately. Shrinking lets running
+    /// Requests finish; no new ones start until the active count falls below
+    /// the new size. `0` means the number of CPU Cores.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// pool.resize(2);
+    /// assert_eq!(pool.size(), 2);
+    /// ```
+    ///
+    /// Requests still run after resizing to `0`
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.body = "ran".to_owned();
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// app.pool().resize(0);
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client
+    ///     .write_all(b"GET / HTTP/1.1\r\nConnection: close\r\n\r\n")
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.starts_with("HTTP/1.1 200 OK"));
+    /// assert!(response.ends_with("ran"));
+    /// # }
+    /// ```
+    pub fn resize(&self, size: usize) {
+        self.state().size = if size == 0 { cpus() } else { size };
+
+        self.inner.notify.notify_waiters();
+    }
+    /// Get Pool Size
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// assert_eq!(pool.size(), 4);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.state().size
+    }
+    /// Get Number of Requests currently running
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// assert_eq!(pool.active(), 0);
+    /// ```
+    pub fn active(&self) -> usize {
+        self.state().active
+    }
+    /// Get Number of Requests waiting for a free Worker
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// assert_eq!(pool.queued(), 0);
+    /// ```
+    pub fn queued(&self) -> usize {
+        self.state().queued
+    }
+    /// Maximum Number of queued Requests (Default: unlimited)
+    ///
+    /// What happens when the Queue is full depends on `backpressure`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::Pool;
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// pool.max_queue(100);
+    /// ```
+    pub fn max_queue(&self, size: usize) {
+        self.state().max_queue = size;
+
+        self.inner.queue_notify.notify_waiters();
+    }
+    /// Backpressure Strategy when the Queue is full (Default: `Block`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::pool::{Backpressure, Pool};
+    ///
+    /// let pool: Pool = Pool::new(4);
+    /// pool.backpressure(Backpressure::Reject);
+    /// ```
+    pub fn backpressure(&self, strategy: Backpressure) {
+        self.state().backpressure = strategy;
+
+        self.inner.queue_notify.notify_waiters();
+    }
+    /*
+     * Wait until the Queue has room (only with `Block`)
+     */
+    pub(crate) async fn ready(&self) {
+        loop {
+            let notified = self.inner.queue_notify.notified();
+            pin!(notified);
+            notified.as_mut().enable();
+
+            {
+                let state: MutexGuard<State> = self.state();
+
+                if state.backpressure == Backpressure::Reject || state.queued < state.max_queue {
+                    return;
+                }
+            }
+
+            notified.await;
+        }
+    }
+    /*
+     * Wait for a free Worker
+     * None when the Queue is full & the Strategy is `Reject`
+     */
+    pub(crate) async fn acquire(&self) -> Option<PoolGuard> {
+        let mut queue: Option<QueueGuard> = None;
+
+        loop {
+            let notified = self.inner.notify.notified();
+            pin!(notified);
+            notified.as_mut().enable();
+
+            {
+                let mut state: MutexGuard<State> = self.state();
+
+                if state.active < state.size {
+                    state.active += 1;
+                    drop(state);
+                    drop(queue);
+
+                    return Some(PoolGuard { pool: self.clone() });
+                }
+
+                if queue.is_none() {
+                    if state.backpressure == Backpressure::Reject && state.queued >= state.max_queue
+                    {
+                        return None;
+                    }
+
+                    state.queued += 1;
+                    queue = Some(QueueGuard { pool: self });
+                }
+            }
+
+            notified.await;
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for PoolGuard {
+    fn drop(&mut self) {
+        self.pool.state().active -= 1;
+
+        self.pool.inner.notify.notify_one();
+    }
+}
+
+impl Drop for QueueGuard<'_> {
+    fn drop(&mut self) {
+        self.pool.state().queued -= 1;
+
+        self.pool.inner.queue_notify.notify_waiters();
+    }
+}
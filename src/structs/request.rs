@@ -1,9 +1,34 @@
+use crate::structs::body_error::BodyError;
+use crate::structs::body_reader::{BodyChunks, BodyReader, BodyStream};
+#[cfg(feature = "json")]
+use crate::structs::json_error::JsonError;
+use crate::structs::multipart::Multipart;
+use crate::structs::multipart_error::MultipartError;
+use crate::structs::uploads::Uploads;
 use crate::utils::get_vec::get_vec;
+use crate::utils::parse_accept::{accept_quality, parse_accept};
+use crate::utils::parse_cookie::parse_cookie;
+use crate::utils::parse_header::{parse_header, HeaderRange};
+use crate::utils::parse_multipart::{header_params, parse_multipart};
+use crate::utils::parse_query::parse_query;
+use crate::utils::read_multipart::read_multipart;
+use bytes::Bytes;
+#[cfg(feature = "json")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "json")]
+use serde_json::error::Category;
+use std::collections::HashMap;
+use std::mem::take;
+use std::net::{IpAddr, SocketAddr};
+use std::ops::Range;
+use tokio::io::AsyncReadExt;
 
 #[derive(Clone, Debug)]
 pub struct Request {
     /// Get Client Address/IP with Port
     ///
+    /// See `remote_addr` for the typed Socket Address.
+    ///
     /// # Example
     ///
     /// ```
@@ -18,38 +43,148 @@ pub struct Request {
     /// app.add(route!("get /", route));
     /// ```
     pub address: String,
-    /// Get Request Header (RAW)
+    pub(crate) remote_addr: SocketAddr,
+    pub(crate) header: String,
+    /*
+     * Store / Cache
+     */
+    pub(crate) header_store: Vec<HeaderRange>,
+    pub(crate) param_store: Vec<(String, String)>,
+    pub(crate) query_store: Vec<(String, String)>,
+    pub method: String,
+    pub url: String,
+    /// Percent-Decoded Request Path (without Query), e.g. `/café` for
+    /// `/caf%C3%A9`. See `raw_path` for the Path as received.
+    pub path: String,
+    /// Request Path as received
+    ///
+    /// Routes are matched on its Segments percent-decoded one by one, so an
+    /// encoded Slash (`%2F`) stays part of its Segment: `/user/a%2Fb` matches
+    /// `/user/:name` with `name` = `a/b`, never `/user/a/b`. Wildcard values
+    /// join the decoded Segments with `/`, losing the difference.
     ///
     /// # Example
     ///
     /// ```
     /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
     ///
-    /// async fn route(c: Context) -> Returns {
-    ///     println!("Path: {}", c.request.header);
+    /// async fn cafe(mut c: Context) -> Returns {
+    ///     c.response.body = format!("{} {}", c.request.path, c.request.raw_path);
     ///     (c, None)
     /// }
     ///
+    /// async fn user(mut c: Context) -> Returns {
+    ///     c.response.body = format!("User: {}", c.request.param("name").await);
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
     /// let mut app = Server::new();
-    /// app.add(route!("get /", route));
+    /// app.add(route!("get /café", cafe));
+    /// app.add(route!("get /user/:name", user));
+    ///
+    /// for (request, expected) in [
+    ///     ("/caf%C3%A9", "/café /caf%C3%A9"),
+    ///     ("/user/a%2Fb", "User: a/b"),
+    ///     ("/user/a/b", "Not Found"),
+    /// ] {
+    ///     let (mut client, stream) = duplex(1024);
+    ///
+    ///     client
+    ///         .write_all(format!("GET {} HTTP/1.1\r\n\r\n", request).as_bytes())
+    ///         .await
+    ///         .unwrap();
+    ///     client.shutdown().await.unwrap();
+    ///
+    ///     app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    ///     let mut response: String = String::new();
+    ///     client.read_to_string(&mut response).await.unwrap();
+    ///
+    ///     assert!(response.ends_with(expected));
+    /// }
+    /// # }
     /// ```
-    pub header: String,
-    /*
-     * Store / Cache
-     */
-    pub(crate) header_store: Vec<(String, String)>,
-    pub(crate) param_store: Vec<(String, String)>,
-    pub(crate) query_store: Vec<(String, String)>,
-    pub method: String,
-    pub url: String,
-    pub path: String,
+    pub raw_path: String,
     pub query: String,
     pub http_version: f64,
+    pub(crate) body: Bytes,
+    pub(crate) body_stream: Option<BodyStream>,
+    pub(crate) body_consumed: bool,
 }
 
 impl Request {
+    /// Get Client Socket Address
+    ///
+    /// The peer address of the connection, which can't be spoofed by the
+    /// Client. Behind a Proxy this is the Proxy (see `forwarded_for`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::SocketAddr;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let remote: SocketAddr = c.request.remote_addr().await;
+    ///     c.response.body = format!("IP: {}", remote.ip());
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn remote_addr(&self) -> SocketAddr {
+        self.remote_addr
+    }
+    /// Get Client IPs from `X-Forwarded-For`
+    ///
+    /// Client first, then the Proxies. Only trust it behind your own Proxy,
+    /// the header is set by the Client otherwise. Invalid entries are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::IpAddr;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     /* X-Forwarded-For: 203.0.113.7, 10.0.0.1 */
+    ///     let forwarded: Vec<IpAddr> = c.request.forwarded_for().await;
+    ///     match forwarded.first() {
+    ///         Some(ip) => c.response.body = format!("Client: {}", ip),
+    ///         None => c.response.body = "No Proxy".to_owned(),
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn forwarded_for(&mut self) -> Vec<IpAddr> {
+        self.header_all("x-forwarded-for")
+            .await
+            .iter()
+            .flat_map(|x: &String| x.split(','))
+            .filter_map(|x: &str| {
+                let x: &str = x.trim();
+                /*
+                 * IP, IP with Port or [IPv6] with Port
+                 */
+                x.parse::<IpAddr>()
+                    .ok()
+                    .or_else(|| x.parse::<SocketAddr>().ok().map(|x: SocketAddr| x.ip()))
+            })
+            .collect()
+    }
     /// Get Request Header
     ///
+    /// Header names are case-insensitive. If a header is repeated, the first
+    /// value is returned (see `header_all`).
+    ///
     /// # Example
     ///
     /// ```
@@ -68,58 +203,174 @@ impl Request {
     /// app.add(route!("get /", route));
     /// ```
     pub async fn header(&mut self, key: &str) -> Option<String> {
-        if !self.header_store.is_empty() {
-            return get_vec(&self.header_store, key.to_owned()).await;
-        }
-
-        let mut found_value: Option<String> = None;
-
-        let mut headers: Vec<(String, String)> = Vec::new();
+        self.parse_header().await;
 
-        let mut h: Vec<&str> = self.header.lines().collect();
-        h.remove(0);
+        self.header_store
+            .iter()
+            .find(|(k, _)| self.header_slice(k).eq_ignore_ascii_case(key))
+            .map(|(_, v)| self.header_slice(v).to_owned())
+    }
+    /// Get All Values of a Repeated Request Header
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let forwarded: Vec<String> = c.request.header_all("x-forwarded-for").await;
+    ///     println!("Forwarded: {}", forwarded.join(", "));
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn header_all(&mut self, key: &str) -> Vec<String> {
+        self.parse_header().await;
 
-        h.into_iter().for_each(|ln: &str| {
-            let mut ln_split: Vec<String> =
-                ln.split_whitespace().map(|s: &str| s.to_owned()).collect();
+        self.header_store
+            .iter()
+            .filter(|(k, _)| self.header_slice(k).eq_ignore_ascii_case(key))
+            .map(|(_, v)| self.header_slice(v).to_owned())
+            .collect()
+    }
+    /// Get All Request Headers
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let headers: Vec<(String, String)> = c.request.headers().await;
+    ///     for (k, v) in headers {
+    ///         println!("{}: {}", k, v);
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn headers(&mut self) -> Vec<(String, String)> {
+        self.parse_header().await;
 
-            if ln_split.is_empty() {
-                return;
-            }
-            /*
-             * Key
-             */
-            let k: String = ln_split[0].trim().replace(':', "");
-            /*
-             * Filter Key
-             */
-            let k: Vec<String> = k.split_whitespace().map(|s: &str| s.to_owned()).collect();
-            let k: String = k.join("");
-            if k.is_empty() {
-                return;
-            }
-            /*
-             * Value
-             */
-            let mut v: String = String::new();
-            ln_split.remove(0);
-
-            if !ln_split.is_empty() {
-                v = ln_split.join(" ");
-            }
+        self.header_store
+            .iter()
+            .map(|(k, v)| {
+                (
+                    self.header_slice(k).to_owned(),
+                    self.header_slice(v).to_owned(),
+                )
+            })
+            .collect()
+    }
+    /// Get Request Header (RAW)
+    ///
+    /// The Request Line & Header Lines as received, without the blank line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(c: Context) -> Returns {
+    ///     println!("Header: {}", c.request.raw_header());
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub fn raw_header(&self) -> &str {
+        &self.header
+    }
+    /// Append a Request Header
+    ///
+    /// Adds a Header Line, e.g. to a Context from `Context::test_request` or
+    /// in a Middleware. Earlier values of the Header are kept, `header`
+    /// returns the first one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::Context;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut c: Context = Context::test_request("get", "/", "").await;
+    /// c.request.append_header("X-Forwarded-For", "10.0.0.1");
+    /// c.request.append_header("X-Forwarded-For", "10.0.0.2");
+    ///
+    /// assert_eq!(
+    ///     c.request.header_all("x-forwarded-for").await,
+    ///     vec!["10.0.0.1".to_owned(), "10.0.0.2".to_owned()]
+    /// );
+    /// assert!(c.request.raw_header().ends_with("\r\nX-Forwarded-For: 10.0.0.2"));
+    /// # }
+    /// ```
+    pub fn append_header(&mut self, key: &str, value: &str) {
+        self.header.push_str(&format!("\r\n{}: {}", key, value));
+        /*
+         * Positions are parsed again on next use
+         */
+        self.header_store.clear();
+    }
+    /*
+     * Header Positions, parsed on first use
+     */
+    async fn parse_header(&mut self) {
+        if self.header_store.is_empty() {
+            self.header_store = parse_header(&self.header).await;
+        }
+    }
+    /*
+     * Header Name or Value sliced out of the Header
+     * `header` only changes through `append_header`, which clears the Positions
+     */
+    fn header_slice(&self, range: &Range<usize>) -> &str {
+        &self.header[range.clone()]
+    }
+    /// Get Request Cookie
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let session: Option<String> = c.request.cookie("session").await;
+    ///     match session {
+    ///         Some(s) => println!("Session is: {}", s),
+    ///         None => c.response.status = 401,
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn cookie(&mut self, name: &str) -> Option<String> {
+        for cookie in self.header_all("cookie").await {
+            let found: Option<String> = parse_cookie(cookie)
+                .await
+                .into_iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v);
 
-            if k == key {
-                found_value = Some(v.clone());
+            if found.is_some() {
+                return found;
             }
+        }
 
-            headers.push((k, v));
-        });
-
-        self.header_store = headers;
-        found_value
+        None
     }
     /// Get Request Parameter
     ///
+    /// Static routes such as `/user/me` take precedence over dynamic routes such
+    /// as `/user/:user`, regardless of registration order.
+    ///
     /// # Example
     ///
     /// ```
@@ -135,14 +386,16 @@ impl Request {
     /// app.add(route!("get /:user", route));
     /// ```
     pub async fn param(&self, key: &str) -> String {
-        let v: Option<String> = get_vec(&self.param_store, key.to_owned()).await;
-        match v {
-            Some(x) => x,
-            None => String::new(),
-        }
+        get_vec(&self.param_store, key.to_owned())
+            .await
+            .unwrap_or_default()
     }
     /// Get Request Query
     ///
+    /// Keys & Values are percent-decoded (`%20` & `+` => space), invalid
+    /// Sequences (`%zz`, `%+f`) are kept as they are. If a key is repeated,
+    /// the first value is returned (see `query_all`).
+    ///
     /// # Example
     ///
     /// ```
@@ -164,46 +417,632 @@ impl Request {
     ///
     /// /* Requested URL: /user?user=John */
     /// app.add(route!("get /", route));
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let mut c: Context = Context::test_request("get", "/?a=%41%2b1&b=%+f%zz", "").await;
+    /// assert_eq!(c.request.query("a").await.as_deref(), Some("A+1"));
+    /// assert_eq!(c.request.query("b").await.as_deref(), Some("% f%zz"));
+    /// # });
     /// ```
     pub async fn query(&mut self, key: &str) -> Option<String> {
-        if !self.query_store.is_empty() {
-            return get_vec(&self.query_store, key.to_owned()).await;
+        if self.query_store.is_empty() {
+            self.query_store = parse_query(self.query.clone()).await;
         }
 
-        let mut found_value: Option<String> = None;
-
-        let query: String = self.query.clone();
+        get_vec(&self.query_store, key.to_owned()).await
+    }
+    /// Get All Values of a Repeated Request Query
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let tags: Vec<String> = c.request.query_all("tag").await;
+    ///     c.response.body = format!("Tags: {}", tags.join(", "));
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    ///
+    /// /* Requested URL: /search?tag=rust&tag=web */
+    /// app.add(route!("get /search", route));
+    /// ```
+    pub async fn query_all(&mut self, key: &str) -> Vec<String> {
+        if self.query_store.is_empty() {
+            self.query_store = parse_query(self.query.clone()).await;
+        }
 
-        if query.is_empty() {
-            return found_value;
+        self.query_store
+            .iter()
+            .filter(|(k, _)| k.to_lowercase() == key.to_lowercase())
+            .map(|(_, v)| v.to_owned())
+            .collect()
+    }
+    /// Get All Request Queries
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let queries: Vec<(String, String)> = c.request.queries().await;
+    ///     for (k, v) in queries {
+    ///         println!("{} = {}", k, v);
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    ///
+    /// /* Requested URL: /search?q=rust&page=2 */
+    /// app.add(route!("get /search", route));
+    /// ```
+    pub async fn queries(&mut self) -> Vec<(String, String)> {
+        if self.query_store.is_empty() {
+            self.query_store = parse_query(self.query.clone()).await;
         }
 
-        let mut query_str: Vec<(String, String)> = Vec::new();
-        let query_split: Vec<String> = query.split('&').map(|s: &str| s.to_owned()).collect();
+        self.query_store.clone()
+    }
+    /// Get Request Body
+    ///
+    /// The Body is buffered on first use, `body`, `json`, `form`, `form_field`
+    /// & `multipart` all read the same buffer & can be called any number of
+    /// times, by Middlewares & the Route alike.
+    ///
+    /// With `Server::stream_body` the Body is buffered only if `body_reader`
+    /// or `multipart_upload` didn't consume it first. Otherwise `body`, `form`,
+    /// `form_field`, `json` & `multipart` return a `Consumed` Error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let body: Vec<u8> = c.request.body().await.unwrap_or_default();
+    ///     c.response.body = format!("Received {} bytes", body.len());
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("post /", route));
+    /// ```
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, middleware, route};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn audit(mut c: Context) -> Returns {
+    ///     let user: Option<String> = c.request.form_field("user").await.unwrap();
+    ///     c.response.set_header("X-User", &user.unwrap_or_default()).await;
+    ///     c.next = true;
+    ///     (c, None)
+    /// }
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let body: Vec<u8> = c.request.body().await.unwrap();
+    ///     let user: Option<String> = c.request.form_field("user").await.unwrap();
+    ///     c.response.body = format!("{} {:?}", body.len(), user);
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.stream_body(true);
+    /// app.add(middleware!(audit));
+    /// app.add(route!("post /", route));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client
+    ///     .write_all(
+    ///         b"POST / HTTP/1.1\r\n\
+    ///           Content-Type: application/x-www-form-urlencoded\r\n\
+    ///           Content-Length: 8\r\n\r\nuser=ann",
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// client.shutdown().await.unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.contains("X-User: ann\r\n"));
+    /// assert!(response.ends_with("8 Some(\"ann\")"));
+    /// # }
+    /// ```
+    pub async fn body(&mut self) -> Result<Vec<u8>, BodyError> {
+        self.load_body().await;
+        self.unconsumed()?;
 
-        query_split.iter().for_each(|q: &String| {
-            let mut kv: Vec<String> = q.split('=').map(|s: &str| s.to_owned()).collect();
+        Ok(self.body.to_vec())
+    }
+    /// Get Request Body Reader
+    ///
+    /// Reads the Body without buffering it when `Server::stream_body` is
+    /// enabled & the Body isn't buffered yet. This consumes the Body: a later
+    /// Reader errors on read & the buffered accessors (see `body`) fail. A
+    /// Body not read to the end closes the connection after the Response.
+    ///
+    /// Otherwise the Reader shares the buffered Body without copying it, the
+    /// Body stays available to further Readers & the buffered accessors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::body_reader::BodyReader;
+    /// use tokio::io::AsyncReadExt;
+    ///
+    /// async fn upload(mut c: Context) -> Returns {
+    ///     let mut reader: BodyReader = c.request.body_reader().await;
+    ///     let mut chunk: [u8; 1024] = [0; 1024];
+    ///     let mut size: usize = 0;
+    ///
+    ///     while let Ok(n) = reader.read(&mut chunk).await {
+    ///         if n == 0 {
+    ///             break;
+    ///         }
+    ///         size += n;
+    ///     }
+    ///
+    ///     c.response.body = format!("Received {} bytes", size);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.stream_body(true);
+    /// app.add(route!("post /upload", upload));
+    /// ```
+    pub async fn body_reader(&mut self) -> BodyReader {
+        /*
+         * Streamed Body is handed out once
+         */
+        if let Some(x) = self.body_stream.take() {
+            self.body_consumed = true;
 
-            if kv.get(0).is_none() || kv[0].is_empty() {
-                return;
+            if let Some(chunks) = x.take() {
+                return BodyReader {
+                    buffer: take(&mut self.body),
+                    position: 0,
+                    chunks: Some(chunks),
+                    consumed: false,
+                };
             }
+        }
+
+        BodyReader {
+            buffer: self.body.clone(),
+            position: 0,
+            chunks: None,
+            consumed: self.body_consumed,
+        }
+    }
+    /*
+     * Read the rest of a streamed Body into the buffered Body
+     * Bytes before a Read Error are kept
+     */
+    async fn load_body(&mut self) {
+        let chunks: BodyChunks = match self.body_stream.take() {
+            Some(x) => match x.take() {
+                Some(x) => x,
+                /*
+                 * Taken by a Clone of the Context
+                 */
+                None => {
+                    self.body_consumed = true;
+                    return;
+                }
+            },
+            None => return,
+        };
 
-            let k: String = kv[0].to_owned();
-            let mut v: String = String::new();
-            kv.remove(0);
+        let mut reader: BodyReader = BodyReader {
+            buffer: take(&mut self.body),
+            position: 0,
+            chunks: Some(chunks),
+            consumed: false,
+        };
+        let mut body: Vec<u8> = Vec::new();
 
-            if !kv.is_empty() {
-                v = kv.join("=");
-            }
+        reader.read_to_end(&mut body).await.ok();
+
+        self.body = Bytes::from(body);
+    }
+    /*
+     * Error when the streamed Body was consumed (see `body`)
+     */
+    fn unconsumed(&self) -> Result<(), BodyError> {
+        match self.body_consumed {
+            true => Err(BodyError::Consumed),
+            false => Ok(()),
+        }
+    }
+    /// Get JSON Request Body
+    ///
+    /// Requires `Content-Type: application/json`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     name: String,
+    ///     age: u32,
+    /// }
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     match c.request.json::<User>().await {
+    ///         Ok(u) => c.response.body = format!("{} is {} years old", u.name, u.age),
+    ///         Err(e) => {
+    ///             c.response.body = e.to_string();
+    ///             c.response.status = 400;
+    ///         }
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("post /user", route));
+    /// ```
+    #[cfg(feature = "json")]
+    pub async fn json<T: DeserializeOwned>(&mut self) -> Result<T, JsonError> {
+        /*
+         * Content Type
+         */
+        let media_type: String = self.media_type().await;
+
+        if media_type != "application/json" && !media_type.ends_with("+json") {
+            return Err(JsonError::ContentType(
+                self.header("content-type").await.unwrap_or_default(),
+            ));
+        }
+        /*
+         * Body
+         */
+        self.load_body().await;
+
+        if self.body_consumed {
+            return Err(JsonError::Consumed);
+        }
+
+        if self.body.is_empty() {
+            return Err(JsonError::Empty);
+        }
+
+        serde_json::from_slice(&self.body).map_err(|e: serde_json::Error| match e.classify() {
+            Category::Data => JsonError::Data(e),
+            _ => JsonError::Syntax(e),
+        })
+    }
+    /// Get Form Request Body
+    ///
+    /// Requires `Content-Type: application/x-www-form-urlencoded`, otherwise
+    /// empty. Errors if the streamed Body was consumed (see `body`). Keys & values are percent-decoded with `+` as space. If a key is
+    /// repeated, the first value is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::collections::HashMap;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     /* Body: name=John+Doe&city=New%20York */
+    ///     let form: HashMap<String, String> = c.request.form().await.unwrap_or_default();
+    ///     c.response.body = format!("{:?}", form.get("name"));
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("post /signup", route));
+    /// ```
+    pub async fn form(&mut self) -> Result<HashMap<String, String>, BodyError> {
+        let mut form: HashMap<String, String> = HashMap::new();
+
+        if self.media_type().await != "application/x-www-form-urlencoded" {
+            return Ok(form);
+        }
+
+        self.load_body().await;
+        self.unconsumed()?;
+
+        parse_query(String::from_utf8_lossy(&self.body).to_string())
+            .await
+            .into_iter()
+            .for_each(|(k, v)| {
+                form.entry(k).or_insert(v);
+            });
+
+        Ok(form)
+    }
+    /// Get Form Request Body Field
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     let name: Option<String> = c.request.form_field("name").await.unwrap_or_default();
+    ///     c.response.body = format!("Hello {}", name.unwrap_or_default());
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("post /signup", route));
+    /// ```
+    pub async fn form_field(&mut self, name: &str) -> Result<Option<String>, BodyError> {
+        Ok(self.form().await?.remove(name))
+    }
+    /// Get Multipart Request Body
+    ///
+    /// Requires `Content-Type: multipart/form-data; boundary=...`. Parts keep
+    /// their headers, field name, optional filename & content. The Request
+    /// Body Size Limit applies (see `Server::max_body_size`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::multipart::Multipart;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn upload(mut c: Context) -> Returns {
+    ///     match c.request.multipart().await {
+    ///         Ok(m) => {
+    ///             let file = m.part("file").unwrap();
+    ///             c.response.body = format!(
+    ///                 "{} {:?} {}",
+    ///                 m.part("title").unwrap().text(),
+    ///                 file.filename(),
+    ///                 file.text()
+    ///             );
+    ///         }
+    ///         Err(e) => {
+    ///             c.response.status = 400;
+    ///             c.response.body = e.to_string();
+    ///         }
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("post /upload", upload));
+    ///
+    /// let body: &str = "--XyZ\r\n\
+    ///     Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+    ///     Notes\r\n\
+    ///     --XyZ\r\n\
+    ///     Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n\
+    ///     Content-Type: text/plain\r\n\r\n\
+    ///     Hello\r\n\
+    ///     --XyZ--\r\n";
+    ///
+    /// let (mut client, stream) = duplex(4096);
+    ///
+    /// client
+    ///     .write_all(
+    ///         format!(
+    ///             "POST /upload HTTP/1.1\r\n\
+    ///              Content-Type: multipart/form-data; boundary=XyZ\r\n\
+    ///              Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+    ///             body.len(),
+    ///             body
+    ///         )
+    ///         .as_bytes(),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.ends_with("Notes Some(\"a.txt\") Hello"));
+    /// # }
+    /// ```
+    pub async fn multipart(&mut self) -> Result<Multipart, MultipartError> {
+        let boundary: String = self.multipart_boundary().await?;
+
+        self.load_body().await;
+
+        if self.body_consumed {
+            return Err(MultipartError::Consumed);
+        }
+
+        parse_multipart(&self.body, &boundary).await
+    }
+    /// Get Multipart Request Body with File Parts on Disk
+    ///
+    /// Like `multipart`, but the Body is read as it arrives (with
+    /// `Server::stream_body` without buffering it) & File Parts are written
+    /// to Temporary Files, see `Uploads` for the Directory & Size Limits. The
+    /// Files are deleted with the Parts, also when the Body fails to parse or
+    /// the Client aborts. Consumes the Body like `body_reader`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::temp_file::TempFile;
+    /// use oxidy::structs::uploads::Uploads;
+    ///
+    /// async fn upload(mut c: Context) -> Returns {
+    ///     let uploads: Uploads = Uploads::new().max_file_size(10 * 1024 * 1024);
+    ///
+    ///     match c.request.multipart_upload(&uploads).await {
+    ///         Ok(m) => {
+    ///             let file: Option<&TempFile> = m.part("file").and_then(|x| x.temp_file());
+    ///             c.response.body = format!("{:?}", file.map(|x: &TempFile| x.size()));
+    ///         }
+    ///         Err(e) => {
+    ///             c.response.status = 400;
+    ///             c.response.body = e.to_string();
+    ///         }
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.stream_body(true);
+    /// app.add(route!("post /upload", upload));
+    /// ```
+    pub async fn multipart_upload(
+        &mut self,
+        uploads: &Uploads,
+    ) -> Result<Multipart, MultipartError> {
+        let boundary: String = self.multipart_boundary().await?;
+
+        let mut reader: BodyReader = self.body_reader().await;
+
+        if reader.consumed {
+            return Err(MultipartError::Consumed);
+        }
+
+        read_multipart(&mut reader, &boundary, uploads).await
+    }
+    /*
+     * Boundary of a `multipart/form-data` Content Type
+     */
+    async fn multipart_boundary(&mut self) -> Result<String, MultipartError> {
+        if self.media_type().await != "multipart/form-data" {
+            return Err(MultipartError::ContentType(
+                self.header("content-type").await.unwrap_or_default(),
+            ));
+        }
 
-            if k == key {
-                found_value = Some(v.clone());
+        let content_type: String = self.header("content-type").await.unwrap_or_default();
+
+        match header_params(&content_type)
+            .into_iter()
+            .find(|(k, _)| k == "boundary")
+        {
+            Some((_, v)) if !v.is_empty() => Ok(v),
+            _ => Err(MultipartError::Boundary),
+        }
+    }
+    /// Check if the Client accepts a Content Type
+    ///
+    /// Follows the `Accept` Header including wildcards (`*/*`, `text/*`) &
+    /// quality values; `q=0` is not accepted. Without the Header any Content
+    /// Type is accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     if c.request.accepts("application/json").await {
+    ///         c.response.content_type = "application/json".to_owned();
+    ///         c.response.body = "{\"name\":\"oxidy\"}".to_owned();
+    ///     } else {
+    ///         c.response.body = "<h1>oxidy</h1>".to_owned();
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn accepts(&mut self, content_type: &str) -> bool {
+        match self.header("accept").await {
+            Some(x) if !x.trim().is_empty() => {
+                accept_quality(&parse_accept(x).await, content_type).await > 0.0
             }
+            _ => true,
+        }
+    }
+    /// Get the preferred Content Type of the Client
+    ///
+    /// Returns the acceptable Content Type with the highest quality value in
+    /// the `Accept` Header, the first one on ties. None when none of them is
+    /// acceptable. Without the Header the first Content Type is preferred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     match c.request.preferred(&["application/json", "text/html"]).await {
+    ///         Some("application/json") => c.response.body = "json".to_owned(),
+    ///         Some(_) => c.response.body = "html".to_owned(),
+    ///         None => c.response.status = 406,
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client
+    ///     .write_all(
+    ///         b"GET / HTTP/1.1\r\nAccept: text/*, application/json;q=0.5, bad, */*;q=x\r\n\
+    ///           Connection: close\r\n\r\n",
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.ends_with("\r\n\r\nhtml"));
+    /// # }
+    /// ```
+    pub async fn preferred<'a>(&mut self, content_types: &[&'a str]) -> Option<&'a str> {
+        let accept: Vec<(String, f32)> = match self.header("accept").await {
+            Some(x) if !x.trim().is_empty() => parse_accept(x).await,
+            _ => return content_types.first().copied(),
+        };
+
+        let mut best: Option<(&'a str, f32)> = None;
 
-            query_str.push((k, v));
-        });
+        for content_type in content_types {
+            let quality: f32 = accept_quality(&accept, content_type).await;
 
-        self.query_store = query_str;
-        found_value
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((content_type, quality));
+            }
+        }
+
+        best.map(|(x, _)| x)
+    }
+    /*
+     * Media Type of Content Type without Parameters (lowercase)
+     */
+    async fn media_type(&mut self) -> String {
+        self.header("content-type")
+            .await
+            .unwrap_or_default()
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase()
     }
 }
@@ -1,8 +1,22 @@
+use crate::structs::chunk_writer::ChunkWriter;
+use crate::structs::cookie::Cookie;
+use crate::structs::payload::{Payload, Stream};
+use crate::structs::sse::Sse;
 use crate::utils::del_vec::del_vec;
 use crate::utils::get_vec::get_vec;
+use crate::utils::mime_type::mime_type;
+use crate::utils::percent_encode::percent_encode;
 use crate::utils::set_vec::set_vec;
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::fs::metadata;
+use tokio::io::ErrorKind;
 
+#[cfg(feature = "json")]
 use serde::Serialize;
+#[cfg(feature = "json")]
 use serde_json::Error;
 
 #[derive(Clone, Debug)]
@@ -52,6 +66,9 @@ pub struct Response {
     pub status: usize,
     /// Get & Set Response Content Type
     ///
+    /// Empty until set, the Response then has `Server::default_content_type`.
+    /// A `Content-Type` header overrides it.
+    ///
     /// # Example
     ///
     /// ```
@@ -71,11 +88,16 @@ pub struct Response {
     /// app.add(route!("get /", route));
     /// ```
     pub content_type: String,
+    pub(crate) payload: Option<Payload>,
 }
 
 impl Response {
     /// Set JSON Response Body
     ///
+    /// Sets `Content-Type: application/json; charset=utf-8` & keeps the current
+    /// status. On serialization failure the body is left untouched & the Error
+    /// is returned.
+    ///
     /// # Example
     ///
     /// ```
@@ -89,29 +111,30 @@ impl Response {
     /// }
     ///
     /// async fn route(mut c: Context) -> Returns {
-    ///     c.response
+    ///     let json: Result<(), serde_json::Error> = c
+    ///         .response
     ///         .json(User {
     ///             name: "John Doe".to_owned(),
     ///             age: 10,
     ///         })
     ///         .await;
     ///
+    ///     if json.is_err() {
+    ///         c.response.status = 500;
+    ///     }
+    ///
     ///     (c, None)
     /// }
     ///
     /// let mut app = Server::new();
     /// app.add(route!("get /", route));
     /// ```
-    pub async fn json(&mut self, value: impl Serialize) {
-        let value: Result<String, Error> = serde_json::to_string(&value);
-        match value {
-            Ok(s) => self.body = s,
-            Err(e) => {
-                self.body = "{}".to_owned();
-                println!("[Error] Fail to serialize json data:\n{}", e);
-            }
-        }
-        self.content_type = "application/json".to_owned();
+    #[cfg(feature = "json")]
+    pub async fn json(&mut self, value: impl Serialize) -> Result<(), Error> {
+        self.body = serde_json::to_string(&value)?;
+        self.content_type = "application/json; charset=utf-8".to_owned();
+
+        Ok(())
     }
     /// Get Response Header
     ///
@@ -138,6 +161,9 @@ impl Response {
     }
     /// Set Response Header
     ///
+    /// Replaces any existing value of the header (see `append_header`).
+    /// Setting `Content-Type` overrides the `content_type` field.
+    ///
     /// # Example
     ///
     /// ```
@@ -154,6 +180,328 @@ impl Response {
     pub async fn set_header(&mut self, key: &str, value: &str) {
         self.header = set_vec(&self.header, key.to_owned(), value.to_owned()).await;
     }
+    /// Append Response Header
+    ///
+    /// Keeps existing values of the header, e.g. for multiple `Set-Cookie`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.append_header("Set-Cookie", "theme=dark").await;
+    ///     c.response.append_header("Set-Cookie", "lang=en").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn append_header(&mut self, key: &str, value: &str) {
+        self.header.push((key.to_owned(), value.to_owned()));
+    }
+    /// Set Response Cookie
+    ///
+    /// Appends a `Set-Cookie` header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::cookie::Cookie;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response
+    ///         .set_cookie(Cookie::new("session", "abcd1234").path("/").http_only(true))
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn set_cookie(&mut self, cookie: Cookie) {
+        self.append_header("Set-Cookie", &cookie.to_string()).await;
+    }
+    /// Send a File as Response Body
+    ///
+    /// Sets the content type from the file extension. The file is streamed
+    /// to the client in chunks with its `Content-Length`, not loaded into
+    /// memory. Returns an Error (e.g. `NotFound`) if the path is not a
+    /// readable file, so the Route can respond `404`. `ETag` & `Last-Modified`
+    /// are set from the file unless already set, `GET` & `HEAD` Requests with
+    /// a current cached copy respond `304`. `GET` Requests with a `Range`
+    /// header respond `206` with the byte range (several as
+    /// `multipart/byteranges`, up to 16) or `416` if none is satisfiable.
+    /// Overlapping & adjacent ranges are merged in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     if c.response.send_file("./reports/2024.pdf").await.is_err() {
+    ///         c.response.status = 404;
+    ///         c.response.body = "Not Found".to_owned();
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /report", route));
+    /// ```
+    pub async fn send_file(&mut self, path: impl AsRef<Path>) -> Result<(), std::io::Error> {
+        let path: PathBuf = path.as_ref().to_path_buf();
+
+        if !metadata(&path).await?.is_file() {
+            return Err(std::io::Error::new(
+                ErrorKind::NotFound,
+                format!("Not a file: {}", path.display()),
+            ));
+        }
+
+        self.content_type = mime_type(&path).await.to_owned();
+        self.payload = Some(Payload::File(path));
+
+        Ok(())
+    }
+    /// Download Response Body as a File
+    ///
+    /// Sets `Content-Disposition: attachment` with the filename, so browsers
+    /// save it instead of displaying it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     if c.response.send_file("./reports/2024.pdf").await.is_ok() {
+    ///         c.response.download_as("Report 2024.pdf").await;
+    ///     }
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /report/download", route));
+    /// ```
+    pub async fn download_as(&mut self, filename: &str) {
+        /*
+         * ASCII Fallback & UTF-8 Filename (RFC 6266)
+         */
+        let fallback: String = filename
+            .chars()
+            .map(|x: char| {
+                if x.is_ascii() && !x.is_ascii_control() && x != '"' && x != '\\' {
+                    x
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        self.set_header(
+            "Content-Disposition",
+            &format!(
+                "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+                fallback,
+                percent_encode(filename).await
+            ),
+        )
+        .await;
+    }
+    /// Stream the Response Body in Chunks
+    ///
+    /// The Function runs after the Response Headers are sent & each
+    /// `ChunkWriter::write` goes to the client as one chunk with
+    /// `Transfer-Encoding: chunked`, so no `Content-Length` is needed up
+    /// front. HTTP/1.0 clients get the raw Body & the Connection is closed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::chunk_writer::ChunkWriter;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.content_type = "text/plain".to_owned();
+    ///     c.response
+    ///         .stream(|mut writer: ChunkWriter| async move {
+    ///             writer.write("Hello ").await?;
+    ///             writer.write("World").await
+    ///         })
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client
+    ///     .write_all(b"GET / HTTP/1.1\r\nConnection: close\r\n\r\n")
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.contains("Transfer-Encoding: chunked\r\n"));
+    /// assert!(!response.contains("Content-Length"));
+    /// assert!(response.ends_with("\r\n\r\n6\r\nHello \r\n5\r\nWorld\r\n0\r\n\r\n"));
+    /// # }
+    /// ```
+    pub async fn stream<F, Fut>(&mut self, func: F)
+    where
+        F: FnOnce(ChunkWriter) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), std::io::Error>> + Send + 'static,
+    {
+        self.body = String::new();
+        self.payload = Some(Payload::Stream(Stream(Arc::new(Mutex::new(Some(
+            Box::new(
+                move |writer: ChunkWriter| -> BoxFuture<'static, Result<(), std::io::Error>> {
+                    Box::pin(func(writer))
+                },
+            ),
+        ))))));
+    }
+    /// Stream Server-Sent Events
+    ///
+    /// Sets the `text/event-stream` Content Type & streams the Events sent by
+    /// the Function (see `Response::stream`). The Connection stays open until
+    /// the Function returns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::sse::Sse;
+    /// use std::net::SocketAddr;
+    /// use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response
+    ///         .sse(|mut sse: Sse| async move {
+    ///             sse.send("update", "{\"count\":1}").await?;
+    ///             sse.send("update", "{\"count\":2}").await
+    ///         })
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let mut app = Server::new();
+    /// app.add(route!("get /events", route));
+    ///
+    /// let (mut client, stream) = duplex(1024);
+    ///
+    /// client
+    ///     .write_all(b"GET /events HTTP/1.1\r\nConnection: close\r\n\r\n")
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080))).await;
+    ///
+    /// let mut response: String = String::new();
+    /// client.read_to_string(&mut response).await.unwrap();
+    ///
+    /// assert!(response.contains("Content-Type: text/event-stream\r\n"));
+    /// assert!(response.contains("event: update\ndata: {\"count\":2}\n\n"));
+    /// # }
+    /// ```
+    pub async fn sse<F, Fut>(&mut self, func: F)
+    where
+        F: FnOnce(Sse) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), std::io::Error>> + Send + 'static,
+    {
+        self.content_type = "text/event-stream".to_owned();
+        self.set_header("Cache-Control", "no-cache").await;
+
+        self.stream(move |writer: ChunkWriter| func(Sse { writer }))
+            .await;
+    }
+    /// Redirect to a Location
+    ///
+    /// Sets the status, the `Location` header & an empty body. Status codes
+    /// other than `301`, `302`, `303`, `307` & `308` (e.g. `304 Not Modified`)
+    /// fall back to `302`. Line breaks in the location are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.redirect("/login", 303).await;
+    ///     assert_eq!(c.response.status, 303);
+    ///
+    ///     c.response.redirect("/login", 200).await;
+    ///     assert_eq!(c.response.status, 302);
+    ///
+    ///     c.response.redirect("/login", 304).await;
+    ///     assert_eq!(c.response.status, 302);
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /account", route));
+    /// ```
+    pub async fn redirect(&mut self, location: &str, status: usize) {
+        let location: String = location.replace(['\r', '\n'], "");
+
+        self.status = match status {
+            301 | 302 | 303 | 307 | 308 => status,
+            _ => 302,
+        };
+        self.body = String::new();
+        self.set_header("Location", &location).await;
+    }
+    /// Redirect Temporarily (`302 Found`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.redirect_temporary("/maintenance").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /", route));
+    /// ```
+    pub async fn redirect_temporary(&mut self, location: &str) {
+        self.redirect(location, 302).await;
+    }
+    /// Redirect Permanently (`301 Moved Permanently`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response.redirect_permanent("/new-blog").await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /blog", route));
+    /// ```
+    pub async fn redirect_permanent(&mut self, location: &str) {
+        self.redirect(location, 301).await;
+    }
     /// Delete Response Header
     ///
     /// # Example
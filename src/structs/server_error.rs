@@ -0,0 +1,91 @@
+use std::error::Error as StdError;
+use std::fmt::{Display, Formatter, Result};
+use std::path::PathBuf;
+use tokio::io::{Error, ErrorKind};
+
+/// Server Error
+///
+/// Passed to the Error Handler of `Server::on_error`. A Client closing the
+/// connection while its Response is written (broken pipe or reset) is a
+/// `Disconnect`, not a `Write` Error: the Response is abandoned & the
+/// default Handler only logs it at `debug` level with the `log` Feature.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::Server;
+/// use oxidy::structs::server_error::ServerError;
+///
+/// let mut app = Server::new();
+/// app.on_error(|e: &ServerError| match e {
+///     ServerError::Panic(x) => eprintln!("route panicked: {}", x),
+///     e => eprintln!("{}", e),
+/// });
+/// ```
+#[derive(Debug)]
+pub enum ServerError {
+    /// Fail to accept a Connection (e.g. too many open files)
+    Accept(Error),
+    /// Fail to complete the TLS Handshake
+    Handshake(Error),
+    /// Fail to read a Request from the Connection
+    Read(Error),
+    /// Fail to write a Response to the Connection
+    Write(Error),
+    /// Client closed the Connection while a Response was written
+    Disconnect(Error),
+    /// Fail to open a File of a Response
+    File(PathBuf, Error),
+    /// Stream Function of a Response returned an Error
+    Stream(Error),
+    /// Route, Tail or Stream Function panicked
+    Panic(String),
+    /// Middleware or Route Function ran longer than `Server::handler_timeout`
+    /// (Method & Path of the Request)
+    Timeout(String),
+}
+
+impl ServerError {
+    /*
+     * Write Error, Broken Pipe & Reset are a Client Disconnect
+     */
+    pub(crate) fn write(e: Error) -> ServerError {
+        match e.kind() {
+            ErrorKind::BrokenPipe | ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted => {
+                ServerError::Disconnect(e)
+            }
+            _ => ServerError::Write(e),
+        }
+    }
+}
+
+impl Display for ServerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ServerError::Accept(e) => write!(f, "Fail to Accept Connection: {}", e),
+            ServerError::Handshake(e) => write!(f, "Fail to TLS Handshake: {}", e),
+            ServerError::Read(e) => write!(f, "Fail to Read Stream: {}", e),
+            ServerError::Write(e) => write!(f, "Fail to Write Stream: {}", e),
+            ServerError::Disconnect(e) => write!(f, "Client Disconnected: {}", e),
+            ServerError::File(x, e) => write!(f, "Fail to Open File {:?}: {}", x, e),
+            ServerError::Stream(e) => write!(f, "Fail to Produce Stream: {}", e),
+            ServerError::Panic(x) => write!(f, "Panic in Function: {}", x),
+            ServerError::Timeout(x) => write!(f, "Function Timed Out: {}", x),
+        }
+    }
+}
+
+impl StdError for ServerError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            ServerError::Accept(e)
+            | ServerError::Handshake(e)
+            | ServerError::Read(e)
+            | ServerError::Write(e)
+            | ServerError::Disconnect(e)
+            | ServerError::File(_, e)
+            | ServerError::Stream(e) => Some(e),
+            ServerError::Panic(_) | ServerError::Timeout(_) => None,
+        }
+    }
+}
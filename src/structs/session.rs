@@ -0,0 +1,210 @@
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Session Values (Key => Value)
+pub type SessionData = HashMap<String, String>;
+
+/// Session Storage Backend
+///
+/// Loads, saves & destroys Session Values by Session ID. Implement it to keep
+/// Sessions in e.g. Redis or a Database, `MemoryStore` is the Default. The
+/// `ttl` passed to `save` is the Session Lifetime.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::session::{SessionData, SessionStore};
+/// use futures::future::BoxFuture;
+/// use std::collections::HashMap;
+/// use std::sync::{Arc, Mutex};
+/// use std::time::Duration;
+///
+/// #[derive(Clone, Default)]
+/// struct LogStore {
+///     sessions: Arc<Mutex<HashMap<String, SessionData>>>,
+/// }
+///
+/// impl SessionStore for LogStore {
+///     fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<SessionData>> {
+///         Box::pin(async move { self.sessions.lock().unwrap().get(id).cloned() })
+///     }
+///
+///     fn save<'a>(&'a self, id: &'a str, data: SessionData, _ttl: Duration) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             println!("Save Session {}", id);
+///             self.sessions.lock().unwrap().insert(id.to_owned(), data);
+///         })
+///     }
+///
+///     fn destroy<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.sessions.lock().unwrap().remove(id);
+///         })
+///     }
+/// }
+/// ```
+pub trait SessionStore: Send + Sync + 'static {
+    /// Load the Values of a Session, `None` if unknown or expired
+    fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<SessionData>>;
+    /// Save the Values of a Session for `ttl`
+    fn save<'a>(&'a self, id: &'a str, data: SessionData, ttl: Duration) -> BoxFuture<'a, ()>;
+    /// Delete a Session
+    fn destroy<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()>;
+}
+
+/// In-Memory Session Store
+///
+/// Sessions live in the process & are lost on restart. Expired Sessions are
+/// evicted while saving. Clones share the same Sessions.
+#[derive(Clone, Default)]
+pub struct MemoryStore {
+    sessions: Arc<Mutex<MemorySessions>>,
+}
+
+#[derive(Default)]
+struct MemorySessions {
+    map: HashMap<String, (SessionData, Instant)>,
+    sweep: Option<Instant>,
+}
+
+impl MemoryStore {
+    /// New empty Memory Store
+    pub fn new() -> MemoryStore {
+        Default::default()
+    }
+
+    fn sessions(&self) -> MutexGuard<'_, MemorySessions> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SessionStore for MemoryStore {
+    fn load<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Option<SessionData>> {
+        Box::pin(async move {
+            match self.sessions().map.get(id) {
+                Some((data, expires)) if *expires > Instant::now() => Some(data.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    fn save<'a>(&'a self, id: &'a str, data: SessionData, ttl: Duration) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let now: Instant = Instant::now();
+            let mut sessions: MutexGuard<MemorySessions> = self.sessions();
+            /*
+             * Evict expired Sessions at most once per Lifetime
+             */
+            if sessions.sweep.is_none_or(|x: Instant| now >= x) {
+                sessions.map.retain(|_, (_, expires)| *expires > now);
+                sessions.sweep = Some(now + ttl);
+            }
+
+            sessions.map.insert(id.to_owned(), (data, now + ttl));
+        })
+    }
+
+    fn destroy<'a>(&'a self, id: &'a str) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            self.sessions().map.remove(id);
+        })
+    }
+}
+
+impl Debug for MemoryStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("MemoryStore")
+    }
+}
+
+/// Session of the Request
+///
+/// Read & changed through `Context::session` when the Session Middleware
+/// (`middlewares::session::Sessions`) is registered. Changes are saved after
+/// the Response has been built. Clones share the same Session.
+#[derive(Clone, Debug)]
+pub struct Session {
+    pub(crate) inner: Arc<Mutex<SessionState>>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SessionState {
+    /*
+     * ID from the Cookie, None for a new Session (created when changed)
+     */
+    pub(crate) id: Option<String>,
+    pub(crate) data: SessionData,
+    pub(crate) changed: bool,
+    pub(crate) destroyed: bool,
+    /*
+     * Previous ID to destroy after `regenerate` or `destroy`
+     */
+    pub(crate) replaced: Option<String>,
+}
+
+impl Session {
+    pub(crate) fn new(id: Option<String>, data: SessionData) -> Session {
+        Session {
+            inner: Arc::new(Mutex::new(SessionState {
+                id,
+                data,
+                ..Default::default()
+            })),
+        }
+    }
+
+    pub(crate) fn state(&self) -> MutexGuard<'_, SessionState> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+    /// Session ID, `None` until a new Session is saved
+    pub async fn id(&self) -> Option<String> {
+        self.state().id.clone()
+    }
+    /// Get Value
+    pub async fn get(&self, key: &str) -> Option<String> {
+        self.state().data.get(key).cloned()
+    }
+    /// Set Value
+    pub async fn set(&self, key: &str, value: &str) {
+        let mut state: MutexGuard<SessionState> = self.state();
+
+        state.data.insert(key.to_owned(), value.to_owned());
+        state.changed = true;
+        state.destroyed = false;
+    }
+    /// Remove Value
+    pub async fn remove(&self, key: &str) {
+        let mut state: MutexGuard<SessionState> = self.state();
+
+        if state.data.remove(key).is_some() {
+            state.changed = true;
+        }
+    }
+    /// Delete the Session from the Store & expire its Cookie
+    ///
+    /// Setting a Value afterwards starts a new Session.
+    pub async fn destroy(&self) {
+        let mut state: MutexGuard<SessionState> = self.state();
+
+        if let Some(x) = state.id.take() {
+            state.replaced = Some(x);
+        }
+        state.data.clear();
+        state.changed = false;
+        state.destroyed = true;
+    }
+    /// Move the Values to a new Session ID, e.g. after Login
+    ///
+    /// Prevents Session Fixation, the old ID is destroyed.
+    pub async fn regenerate(&self) {
+        let mut state: MutexGuard<SessionState> = self.state();
+
+        if let Some(x) = state.id.take() {
+            state.replaced = Some(x);
+        }
+        state.changed = true;
+    }
+}
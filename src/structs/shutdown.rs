@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use tokio::sync::watch::{channel, Receiver, Sender};
+
+/// Shutdown Handle
+///
+/// Stops a Server started with `Server::run` (or `run_tls`). Clones share the
+/// same Handle, so one from `Server::shutdown_handle` stops the running
+/// Server from anywhere, e.g. a signal handler or a test.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::shutdown::Shutdown;
+///
+/// let shutdown: Shutdown = Shutdown::new();
+/// assert!(!shutdown.is_shutdown());
+///
+/// shutdown.shutdown();
+/// assert!(shutdown.is_shutdown());
+/// ```
+#[derive(Clone)]
+pub struct Shutdown {
+    sender: Arc<Sender<bool>>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Shutdown {
+        let (sender, _): (Sender<bool>, Receiver<bool>) = channel(false);
+
+        Shutdown {
+            sender: Arc::new(sender),
+        }
+    }
+}
+
+impl Shutdown {
+    /// New Shutdown Handle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::shutdown::Shutdown;
+    ///
+    /// let shutdown: Shutdown = Shutdown::new();
+    /// ```
+    pub fn new() -> Shutdown {
+        Default::default()
+    }
+    /// Request Shutdown
+    ///
+    /// The Server stops accepting new connections, waits for in-flight
+    /// Requests to finish & then returns. Calling it again has no effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::shutdown::Shutdown;
+    ///
+    /// let shutdown: Shutdown = Shutdown::new();
+    /// shutdown.shutdown();
+    /// shutdown.shutdown();
+    /// assert!(shutdown.is_shutdown());
+    /// ```
+    pub fn shutdown(&self) {
+        self.sender.send_replace(true);
+    }
+    /// Whether Shutdown was requested
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::shutdown::Shutdown;
+    ///
+    /// let shutdown: Shutdown = Shutdown::new();
+    /// assert!(!shutdown.is_shutdown());
+    /// ```
+    pub fn is_shutdown(&self) -> bool {
+        *self.sender.borrow()
+    }
+    /// Wait until Shutdown is requested
+    ///
+    /// Completes immediately if it already was.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::structs::shutdown::Shutdown;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let shutdown: Shutdown = Shutdown::new();
+    /// let handle: Shutdown = shutdown.clone();
+    ///
+    /// tokio::spawn(async move { handle.shutdown() });
+    ///
+    /// shutdown.wait().await;
+    /// assert!(shutdown.is_shutdown());
+    /// # }
+    /// ```
+    pub async fn wait(&self) {
+        let mut receiver: Receiver<bool> = self.sender.subscribe();
+        /*
+         * Sender is owned by the Handle, never closed while waiting
+         */
+        while !*receiver.borrow_and_update() {
+            if receiver.changed().await.is_err() {
+                return;
+            }
+        }
+    }
+}
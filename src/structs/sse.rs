@@ -0,0 +1,112 @@
+use crate::structs::chunk_writer::ChunkWriter;
+use tokio::io::Error;
+
+/// Server-Sent Events Writer
+///
+/// Writes `event:` & `data:` lines of a `text/event-stream` Response, each
+/// Event sent to the client immediately. See `Response::sse`.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::sse::Sse;
+/// use std::time::Duration;
+///
+/// async fn route(mut c: Context) -> Returns {
+///     c.response
+///         .sse(|mut sse: Sse| async move {
+///             loop {
+///                 /* Error once the client is gone */
+///                 sse.send("tick", "{\"cpu\":42}").await?;
+///                 tokio::time::sleep(Duration::from_secs(1)).await;
+///             }
+///         })
+///         .await;
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("get /events", route));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sse {
+    pub(crate) writer: ChunkWriter,
+}
+
+impl Sse {
+    /// Send an Event
+    ///
+    /// An empty Event Name sends an unnamed (`message`) Event. Multiline Data
+    /// is split into `data:` lines. Returns a `BrokenPipe` Error once the
+    /// client is gone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::sse::Sse;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response
+    ///         .sse(|mut sse: Sse| async move {
+    ///             sse.send("", "unnamed").await?;
+    ///             sse.send("notice", "line 1\nline 2").await
+    ///         })
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /events", route));
+    /// ```
+    pub async fn send(&mut self, event: &str, data: &str) -> Result<(), Error> {
+        let mut message: String = String::new();
+
+        let event: String = event.replace(['\r', '\n'], "");
+
+        if !event.is_empty() {
+            message.push_str(&format!("event: {}\n", event));
+        }
+
+        data.replace("\r\n", "\n")
+            .split(['\r', '\n'])
+            .for_each(|x: &str| message.push_str(&format!("data: {}\n", x)));
+
+        message.push('\n');
+
+        self.writer.write(message).await
+    }
+    /// Send a Comment
+    ///
+    /// Ignored by clients, e.g. a heartbeat keeping proxies from closing an
+    /// idle Connection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use oxidy::{Server, Context, Returns, route};
+    /// use oxidy::structs::sse::Sse;
+    /// use std::time::Duration;
+    ///
+    /// async fn route(mut c: Context) -> Returns {
+    ///     c.response
+    ///         .sse(|mut sse: Sse| async move {
+    ///             loop {
+    ///                 sse.comment("heartbeat").await?;
+    ///                 tokio::time::sleep(Duration::from_secs(15)).await;
+    ///             }
+    ///         })
+    ///         .await;
+    ///     (c, None)
+    /// }
+    ///
+    /// let mut app = Server::new();
+    /// app.add(route!("get /events", route));
+    /// ```
+    pub async fn comment(&mut self, text: &str) -> Result<(), Error> {
+        self.writer
+            .write(format!(": {}\n\n", text.replace(['\r', '\n'], " ")))
+            .await
+    }
+}
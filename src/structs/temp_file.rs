@@ -0,0 +1,96 @@
+use crate::utils::random_token::random_token;
+use std::path::{Path, PathBuf};
+use tokio::fs::{copy, remove_file, rename, File, OpenOptions};
+use tokio::io::Error;
+
+/// Temporary File of an uploaded Multipart Part
+///
+/// Created by `Request::multipart_upload` in the Temp Directory of
+/// `Uploads`. The File is deleted when the last `Part` holding it is dropped
+/// (also when the Request fails or is abandoned), `persist` keeps it.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::temp_file::TempFile;
+/// use oxidy::structs::uploads::Uploads;
+///
+/// async fn upload(mut c: Context) -> Returns {
+///     let uploads: Uploads = Uploads::new();
+///
+///     if let Ok(m) = c.request.multipart_upload(&uploads).await {
+///         if let Some(file) = m.part("video").and_then(|x| x.temp_file()) {
+///             println!("{} bytes at {:?}", file.size(), file.path());
+///
+///             if file.persist("/srv/videos/upload.mp4").await.is_err() {
+///                 c.response.status = 500;
+///             }
+///         }
+///     }
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("post /upload", upload));
+/// ```
+#[derive(Debug)]
+pub struct TempFile {
+    pub(crate) path: PathBuf,
+    pub(crate) size: u64,
+}
+
+impl TempFile {
+    /*
+     * New empty File with a random Name in the Directory
+     */
+    pub(crate) async fn create(dir: &Path) -> Result<(TempFile, File), Error> {
+        let path: PathBuf = dir.join(format!("oxidy-upload-{}", random_token(16)));
+
+        let file: File = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .await?;
+
+        Ok((TempFile { path, size: 0 }, file))
+    }
+    /// Path of the Temporary File
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Size in Bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+    /// Move the File to a Path
+    ///
+    /// Renamed, or copied when the Path is on another File System. `path` &
+    /// other Clones of the Part no longer find the Temporary File afterwards.
+    pub async fn persist(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        if rename(&self.path, path.as_ref()).await.is_ok() {
+            return Ok(());
+        }
+
+        copy(&self.path, path).await?;
+        remove_file(&self.path).await
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let path: PathBuf = std::mem::take(&mut self.path);
+
+        /*
+         * Remove the File off the Runtime, Blocking only outside of it
+         */
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn_blocking(move || std::fs::remove_file(path).ok());
+            }
+            Err(_) => {
+                std::fs::remove_file(path).ok();
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use handlebars::Handlebars;
+
+/*
+ * Templates loaded by `Server::templates`, kept in the Application States
+ */
+pub(crate) struct Templates(pub(crate) Handlebars<'static>);
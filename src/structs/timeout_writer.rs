@@ -0,0 +1,99 @@
+use std::future::Future;
+use std::io::IoSlice;
+use std::pin::Pin;
+use std::task::{Context as TaskContext, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncWrite, Error, ErrorKind};
+use tokio::time::{sleep, Sleep};
+
+/*
+ * Writer failing with `TimedOut` when a Write, Flush or Shutdown makes no
+ * progress within the Duration (client stopped reading)
+ * The Deadline restarts with every completed Operation
+ */
+pub(crate) struct TimeoutWriter<W> {
+    writer: W,
+    duration: Option<Duration>,
+    deadline: Option<Pin<Box<Sleep>>>,
+}
+
+impl<W: AsyncWrite + Unpin> TimeoutWriter<W> {
+    pub(crate) fn new(writer: W, duration: Option<Duration>) -> TimeoutWriter<W> {
+        TimeoutWriter {
+            writer,
+            duration,
+            deadline: None,
+        }
+    }
+    /*
+     * Track the Deadline of a pending Operation
+     */
+    fn poll_timeout<T>(
+        &mut self,
+        cx: &mut TaskContext<'_>,
+        poll: Poll<Result<T, Error>>,
+    ) -> Poll<Result<T, Error>> {
+        let duration: Duration = match (&poll, self.duration) {
+            (Poll::Pending, Some(x)) => x,
+            _ => {
+                self.deadline = None;
+                return poll;
+            }
+        };
+
+        let deadline: &mut Pin<Box<Sleep>> = self
+            .deadline
+            .get_or_insert_with(|| Box::pin(sleep(duration)));
+
+        match deadline.as_mut().poll(cx) {
+            Poll::Ready(_) => {
+                self.deadline = None;
+                Poll::Ready(Err(Error::new(ErrorKind::TimedOut, "Write Timeout")))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for TimeoutWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, Error>> {
+        let this: &mut TimeoutWriter<W> = self.get_mut();
+        let poll: Poll<Result<usize, Error>> = Pin::new(&mut this.writer).poll_write(cx, buf);
+
+        this.poll_timeout(cx, poll)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut TaskContext<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<Result<usize, Error>> {
+        let this: &mut TimeoutWriter<W> = self.get_mut();
+        let poll: Poll<Result<usize, Error>> =
+            Pin::new(&mut this.writer).poll_write_vectored(cx, bufs);
+
+        this.poll_timeout(cx, poll)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.writer.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Result<(), Error>> {
+        let this: &mut TimeoutWriter<W> = self.get_mut();
+        let poll: Poll<Result<(), Error>> = Pin::new(&mut this.writer).poll_flush(cx);
+
+        this.poll_timeout(cx, poll)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Result<(), Error>> {
+        let this: &mut TimeoutWriter<W> = self.get_mut();
+        let poll: Poll<Result<(), Error>> = Pin::new(&mut this.writer).poll_shutdown(cx);
+
+        this.poll_timeout(cx, poll)
+    }
+}
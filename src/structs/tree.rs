@@ -0,0 +1,245 @@
+use crate::utils::percent_decode::percent_decode;
+use std::collections::HashMap;
+
+/*
+ * Route Entry (Index of `Server::adds`, Method, Parameter Names)
+ */
+type Entry = (usize, String, Vec<String>);
+/*
+ * Matched Route (Index of `Server::adds`, Parameters)
+ */
+pub(crate) type Found = (usize, Vec<(String, String)>);
+
+/*
+ * Segment Trie of Route Paths (one HashMap Node per Path Segment)
+ * Precedence: Static > Parameter (`:id`) > Wildcard (`*path`)
+ */
+#[derive(Clone)]
+pub(crate) struct Tree {
+    root: Node,
+    pub(crate) case_insensitive: bool,
+    pub(crate) strict_trailing_slash: bool,
+}
+
+#[derive(Clone, Default)]
+struct Node {
+    statics: HashMap<String, Node>,
+    param: Option<Box<Node>>,
+    wildcards: Vec<Entry>,
+    routes: Vec<Entry>,
+}
+
+impl Default for Tree {
+    fn default() -> Tree {
+        Tree::new(true, false)
+    }
+}
+
+impl Tree {
+    /*
+     * Empty Tree with Matching Options
+     */
+    pub(crate) fn new(case_insensitive: bool, strict_trailing_slash: bool) -> Tree {
+        Tree {
+            root: Node::default(),
+            case_insensitive,
+            strict_trailing_slash,
+        }
+    }
+    /*
+     * Insert Route
+     * Replaces the Route of the same Method & Path, returns its Index
+     */
+    pub(crate) fn insert(&mut self, index: usize, method: &str, path: &str) -> Option<usize> {
+        let segments: Vec<String> = self.segments(path);
+        let mut node: &mut Node = &mut self.root;
+        let mut names: Vec<String> = Vec::new();
+
+        for segment in segments {
+            if let Some(name) = segment.strip_prefix('*') {
+                names.push(name.to_owned());
+                return replace(&mut node.wildcards, (index, method.to_lowercase(), names));
+            }
+
+            if let Some(name) = segment.strip_prefix(':') {
+                names.push(name.to_owned());
+                node = node.param.get_or_insert_with(Default::default);
+            } else {
+                node = node
+                    .statics
+                    .entry(key(&segment, self.case_insensitive))
+                    .or_default();
+            }
+        }
+
+        replace(&mut node.routes, (index, method.to_lowercase(), names))
+    }
+    /*
+     * Find Routes of the Method matching the Path with Parameters
+     * Single Traversal with Backtracking in Precedence Order
+     */
+    pub(crate) fn find(&self, method: &str, path: &str) -> Vec<Found> {
+        let segments: Vec<String> = self.segments(path);
+        let mut values: Vec<String> = Vec::new();
+
+        self.root
+            .find_segments(self, &method.to_lowercase(), &segments, &mut values)
+            .unwrap_or_default()
+    }
+    /*
+     * Get Registered Methods of all Routes matching the Path
+     * In Registration Order
+     */
+    pub(crate) fn methods(&self, path: &str) -> Vec<String> {
+        let segments: Vec<String> = self.segments(path);
+        let mut entries: Vec<&Entry> = Vec::new();
+
+        self.root.entries(self, &segments, &mut entries);
+        entries.sort_unstable_by_key(|(index, _, _)| *index);
+
+        let mut methods: Vec<String> = Vec::new();
+
+        entries.into_iter().for_each(|(_, method, _)| {
+            let method: String = method.to_uppercase();
+
+            if !methods.contains(&method) {
+                methods.push(method);
+            }
+        });
+
+        methods
+    }
+    /*
+     * Percent-Decoded Path Segments
+     * Split before decoding, so `%2F` stays inside its Segment
+     * Empty Segments are skipped, a Trailing Slash is kept as a final empty
+     * Segment when strict
+     */
+    fn segments(&self, path: &str) -> Vec<String> {
+        let mut segments: Vec<String> = path
+            .split('/')
+            .filter(|x: &&str| !x.is_empty())
+            .map(|x: &str| percent_decode(x, false))
+            .collect();
+
+        if self.strict_trailing_slash && !segments.is_empty() && path.ends_with('/') {
+            segments.push(String::new());
+        }
+
+        segments
+    }
+}
+
+impl Node {
+    fn find_segments(
+        &self,
+        tree: &Tree,
+        method: &str,
+        segments: &[String],
+        values: &mut Vec<String>,
+    ) -> Option<Vec<Found>> {
+        /*
+         * End of Path
+         */
+        if segments.is_empty() {
+            let found: Vec<Found> = matched(&self.routes, method, values);
+
+            if !found.is_empty() {
+                return Some(found);
+            }
+        }
+        /*
+         * Static
+         */
+        if let Some(first) = segments.first() {
+            if let Some(node) = self.statics.get(&key(first, tree.case_insensitive)) {
+                if let Some(found) = node.find_segments(tree, method, &segments[1..], values) {
+                    return Some(found);
+                }
+            }
+            /*
+             * Parameter (never the empty Trailing Slash Segment)
+             */
+            if let Some(node) = self.param.as_ref().filter(|_| !first.is_empty()) {
+                values.push(first.to_string());
+
+                if let Some(found) = node.find_segments(tree, method, &segments[1..], values) {
+                    return Some(found);
+                }
+
+                values.pop();
+            }
+        }
+        /*
+         * Wildcard
+         */
+        values.push(segments.join("/"));
+
+        let found: Vec<Found> = matched(&self.wildcards, method, values);
+
+        values.pop();
+
+        if found.is_empty() {
+            return None;
+        }
+
+        Some(found)
+    }
+
+    fn entries<'a>(&'a self, tree: &Tree, segments: &[String], entries: &mut Vec<&'a Entry>) {
+        entries.extend(self.wildcards.iter());
+
+        match segments.first() {
+            None => entries.extend(self.routes.iter()),
+            Some(first) => {
+                if let Some(node) = self.statics.get(&key(first, tree.case_insensitive)) {
+                    node.entries(tree, &segments[1..], entries);
+                }
+                if let Some(node) = self.param.as_ref().filter(|_| !first.is_empty()) {
+                    node.entries(tree, &segments[1..], entries);
+                }
+            }
+        }
+    }
+}
+/*
+ * Routes of the Method with named Parameter Values
+ */
+fn matched(entries: &[Entry], method: &str, values: &[String]) -> Vec<Found> {
+    entries
+        .iter()
+        .filter(|(_, m, _)| m == method)
+        .map(|(index, _, names)| {
+            let param: Vec<(String, String)> = names
+                .iter()
+                .cloned()
+                .zip(values.iter().cloned())
+                .filter(|(k, _)| !k.is_empty())
+                .collect();
+
+            (*index, param)
+        })
+        .collect()
+}
+/*
+ * Static Segment Key
+ */
+fn key(segment: &str, case_insensitive: bool) -> String {
+    if case_insensitive {
+        return segment.to_lowercase();
+    }
+
+    segment.to_owned()
+}
+/*
+ * Push the Entry or replace the one of its Method
+ */
+fn replace(entries: &mut Vec<Entry>, entry: Entry) -> Option<usize> {
+    match entries.iter_mut().find(|(_, method, _)| *method == entry.1) {
+        Some(x) => Some(std::mem::replace(x, entry).0),
+        None => {
+            entries.push(entry);
+            None
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+/// Multipart Upload Options Builder
+///
+/// Options of `Request::multipart_upload`: File Parts (with a Filename) are
+/// streamed to a `TempFile` in the Temp Directory (Default: the OS Temp
+/// Directory) & may be up to `max_file_size` (Default: 100 MiB), other Fields
+/// are kept in Memory up to `max_field_size` (Default: 64 KiB). Larger Parts
+/// fail with `MultipartError::TooLarge`. The whole Body is still limited by
+/// `Server::max_body_size` (Default: 2 MiB), raise it for larger Uploads.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::structs::uploads::Uploads;
+///
+/// let uploads: Uploads = Uploads::new()
+///     .temp_dir("/var/tmp")
+///     .max_file_size(1024 * 1024 * 1024)
+///     .max_field_size(16 * 1024);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Uploads {
+    pub(crate) temp_dir: PathBuf,
+    pub(crate) max_file_size: usize,
+    pub(crate) max_field_size: usize,
+}
+
+impl Default for Uploads {
+    fn default() -> Uploads {
+        Uploads {
+            temp_dir: std::env::temp_dir(),
+            max_file_size: 100 * 1024 * 1024,
+            max_field_size: 64 * 1024,
+        }
+    }
+}
+
+impl Uploads {
+    /// New Upload Options (OS Temp Directory, 100 MiB Files, 64 KiB Fields)
+    pub fn new() -> Uploads {
+        Default::default()
+    }
+    /// Set the Directory of Temporary Files
+    pub fn temp_dir(mut self, dir: impl AsRef<Path>) -> Uploads {
+        self.temp_dir = dir.as_ref().to_path_buf();
+        self
+    }
+    /// Maximum Size of a File Part in Bytes
+    ///
+    /// Capped by `Server::max_body_size`, which applies to the whole Body.
+    pub fn max_file_size(mut self, bytes: usize) -> Uploads {
+        self.max_file_size = bytes;
+        self
+    }
+    /// Maximum Size of a Field Part in Bytes
+    pub fn max_field_size(mut self, bytes: usize) -> Uploads {
+        self.max_field_size = bytes;
+        self
+    }
+}
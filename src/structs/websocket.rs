@@ -0,0 +1,85 @@
+use futures::future::BoxFuture;
+use tokio::io::{Error, ErrorKind};
+use tokio::sync::mpsc::{Receiver, Sender};
+
+/// WebSocket Message
+///
+/// Fragmented Messages are received whole. `Ping`s are answered with a
+/// `Pong` automatically, `Close` carries the Status Code & Reason.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(Option<(u16, String)>),
+}
+
+/// WebSocket Connection
+///
+/// Requires the `websocket` feature. Handed to the Function of
+/// `Context::websocket` after the Handshake. `recv` returns `None` once the
+/// connection is closed, dropping the WebSocket closes it (`1000`). Receive
+/// Messages regularly, the connection waits for a full queue.
+///
+/// # Example
+///
+/// ```
+/// use oxidy::{Server, Context, Returns, route};
+/// use oxidy::structs::websocket::{Message, WebSocket};
+///
+/// async fn echo(mut c: Context) -> Returns {
+///     let upgraded: bool = c
+///         .websocket(|mut ws: WebSocket| async move {
+///             while let Some(message) = ws.recv().await {
+///                 match message {
+///                     Message::Text(x) => {
+///                         if ws.send(Message::Text(x)).await.is_err() {
+///                             break;
+///                         }
+///                     }
+///                     Message::Close(_) => break,
+///                     _ => {}
+///                 }
+///             }
+///         })
+///         .await;
+///
+///     if !upgraded {
+///         c.response.status = 400;
+///         c.response.body = "WebSocket Handshake expected".to_owned();
+///     }
+///     (c, None)
+/// }
+///
+/// let mut app = Server::new();
+/// app.add(route!("get /ws", echo));
+/// ```
+#[derive(Debug)]
+pub struct WebSocket {
+    pub(crate) incoming: Receiver<Message>,
+    pub(crate) outgoing: Sender<Message>,
+}
+
+pub(crate) type WebSocketFunc = Box<dyn FnOnce(WebSocket) -> BoxFuture<'static, ()> + Send>;
+
+impl WebSocket {
+    /// Receive the next Message
+    pub async fn recv(&mut self) -> Option<Message> {
+        self.incoming.recv().await
+    }
+    /// Send a Message
+    ///
+    /// Returns a `BrokenPipe` Error once the connection is closed.
+    pub async fn send(&self, message: Message) -> Result<(), Error> {
+        self.outgoing
+            .send(message)
+            .await
+            .map_err(|_| Error::new(ErrorKind::BrokenPipe, "WebSocket closed"))
+    }
+    /// Close the connection with a Status Code & Reason
+    pub async fn close(self, code: u16, reason: &str) -> Result<(), Error> {
+        self.send(Message::Close(Some((code, reason.to_owned()))))
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+/*
+ * Decode Standard Base64 (RFC 4648) with optional Padding
+ * None on invalid Characters or Length
+ */
+pub(crate) fn base64_decode(value: &str) -> Option<Vec<u8>> {
+    let value: &str = value.trim_end_matches('=');
+    let mut bytes: Vec<u8> = Vec::with_capacity(value.len() * 3 / 4);
+    let mut bits: u32 = 0;
+    let mut count: u8 = 0;
+
+    for x in value.bytes() {
+        let sextet: u32 = match x {
+            b'A'..=b'Z' => x - b'A',
+            b'a'..=b'z' => x - b'a' + 26,
+            b'0'..=b'9' => x - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        } as u32;
+
+        bits = (bits << 6) | sextet;
+        count += 1;
+
+        if count == 4 {
+            bytes.extend_from_slice(&[(bits >> 16) as u8, (bits >> 8) as u8, bits as u8]);
+            bits = 0;
+            count = 0;
+        }
+    }
+    /*
+     * Remaining 2 or 3 Characters hold 1 or 2 Bytes
+     */
+    match count {
+        0 => {}
+        2 => bytes.push((bits >> 4) as u8),
+        3 => bytes.extend_from_slice(&[(bits >> 10) as u8, (bits >> 2) as u8]),
+        _ => return None,
+    }
+
+    Some(bytes)
+}
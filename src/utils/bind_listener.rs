@@ -0,0 +1,43 @@
+use std::net::SocketAddr;
+use tokio::io::{Error, ErrorKind};
+use tokio::net::{lookup_host, TcpListener, TcpSocket, ToSocketAddrs};
+
+/*
+ * Bind TCP Listener with Socket Options
+ * Tries every resolved Address, returns the last Error if none binds
+ * `SO_REUSEPORT` only exists on Unix & is ignored elsewhere
+ */
+pub(crate) async fn bind_listener(
+    address: impl ToSocketAddrs,
+    reuse_address: bool,
+    reuse_port: bool,
+) -> Result<TcpListener, Error> {
+    let mut last_error: Error = Error::new(ErrorKind::InvalidInput, "No Address to bind");
+
+    for address in lookup_host(address).await? {
+        match bind(address, reuse_address, reuse_port) {
+            Ok(x) => return Ok(x),
+            Err(e) => last_error = e,
+        }
+    }
+
+    Err(last_error)
+}
+
+fn bind(address: SocketAddr, reuse_address: bool, reuse_port: bool) -> Result<TcpListener, Error> {
+    let socket: TcpSocket = match address {
+        SocketAddr::V4(_) => TcpSocket::new_v4()?,
+        SocketAddr::V6(_) => TcpSocket::new_v6()?,
+    };
+
+    socket.set_reuseaddr(reuse_address)?;
+
+    #[cfg(all(unix, not(target_os = "solaris"), not(target_os = "illumos")))]
+    socket.set_reuseport(reuse_port)?;
+
+    #[cfg(not(all(unix, not(target_os = "solaris"), not(target_os = "illumos"))))]
+    let _ = reuse_port;
+
+    socket.bind(address)?;
+    socket.listen(1024)
+}
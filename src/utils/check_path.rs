@@ -0,0 +1,44 @@
+/*
+ * Validate Route Path at Registration Time
+ */
+pub(crate) fn check_path(path: &str) {
+    /*
+     * Any Path (Middleware)
+     */
+    if path == "*" {
+        return;
+    }
+    /*
+     * Absolute Path without Whitespace & Control Characters
+     * Such Paths never match a Request
+     */
+    if !path.starts_with('/') {
+        panic!("[Error] Path must start with '/': {:?}", path);
+    }
+
+    if path
+        .chars()
+        .any(|x: char| x.is_whitespace() || x.is_control())
+    {
+        panic!(
+            "[Error] Path must not contain whitespace or control characters: {:?}",
+            path
+        );
+    }
+
+    let path_split: Vec<&str> = path.split('/').filter(|x: &&str| !x.is_empty()).collect();
+    /*
+     * Wildcard must be the Final Segment
+     */
+    if path_split
+        .iter()
+        .rev()
+        .skip(1)
+        .any(|x: &&str| x.starts_with('*'))
+    {
+        panic!(
+            "[Error] Wildcard must be the final segment of the path: {}",
+            path
+        );
+    }
+}
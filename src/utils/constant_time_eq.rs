@@ -0,0 +1,13 @@
+/*
+ * Compare Bytes in Time depending only on the longer Length
+ * No early return on the first differing Byte (Secrets, Passwords)
+ */
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let mut diff: usize = a.len() ^ b.len();
+
+    for i in 0..a.len().max(b.len()) {
+        diff |= (a.get(i).copied().unwrap_or(0) ^ b.get(i).copied().unwrap_or(0)) as usize;
+    }
+
+    diff == 0
+}
@@ -0,0 +1,9 @@
+use std::num::NonZeroUsize;
+use std::thread::available_parallelism;
+
+/*
+ * Number of CPU Cores (logical, at least 1)
+ */
+pub(crate) fn cpus() -> usize {
+    available_parallelism().map(NonZeroUsize::get).unwrap_or(1)
+}
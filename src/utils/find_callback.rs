@@ -1,3 +1,5 @@
+use crate::utils::percent_decode::percent_decode;
+
 /*
  * Get Callback for Route in Parallel
  */
@@ -6,14 +8,18 @@ pub(crate) struct IsFind {
     pub(crate) param: Vec<(String, String)>,
 }
 
-pub(crate) async fn find_callback(path: String, callback_path: String) -> IsFind {
+pub(crate) async fn find_callback(
+    path: String,
+    callback_path: String,
+    case_insensitive: bool,
+) -> IsFind {
     /*
-     * Path Split
+     * Path Split, Segments percent-decoded like in the Segment Trie
      */
     let path_split: Vec<String> = path
         .split('/')
         .filter(|x: &&str| !x.is_empty())
-        .map(|x: &str| x.to_owned())
+        .map(|x: &str| percent_decode(x, false))
         .collect();
     /*
      * Callback Path Split
@@ -24,10 +30,24 @@ pub(crate) async fn find_callback(path: String, callback_path: String) -> IsFind
         .filter(|x: &&str| !x.is_empty())
         .map(|x: &str| x.to_owned())
         .collect();
+    /*
+     * Wildcard (Catch-All) as Final Segment
+     */
+    let wildcard: Option<String> = match callback_path_split.last() {
+        Some(x) if x.starts_with('*') => Some(x.replacen('*', "", 1)),
+        _ => None,
+    };
+
+    let fixed_len: usize = match wildcard {
+        Some(_) => callback_path_split.len() - 1,
+        None => callback_path_split.len(),
+    };
     /*
      * Check Split Length
      */
-    if callback_path_split.len() != path_split.len() {
+    if (wildcard.is_none() && fixed_len != path_split.len())
+        || (wildcard.is_some() && fixed_len > path_split.len())
+    {
         return IsFind {
             find: false,
             param: Vec::new(),
@@ -38,7 +58,8 @@ pub(crate) async fn find_callback(path: String, callback_path: String) -> IsFind
     let mut param: Vec<(String, String)> = Vec::new();
 
     callback_path_split
-        .into_iter()
+        .iter()
+        .take(fixed_len)
         .enumerate()
         .for_each(|(i, callback_path_elm)| {
             let callback_path_char: char = callback_path_elm
@@ -46,9 +67,15 @@ pub(crate) async fn find_callback(path: String, callback_path: String) -> IsFind
                 .next()
                 .expect("[Error] Fail to convert from path string to path char");
             /*
-             * Static
+             * Static (Case-Insensitive like the Segment Trie unless disabled)
              */
-            if callback_path_elm == path_split[i] {
+            let path_elm: String = if case_insensitive {
+                path_split[i].to_lowercase()
+            } else {
+                path_split[i].to_owned()
+            };
+
+            if *callback_path_elm == path_elm {
                 prepare_path.push_str(&format!("/{}", callback_path_elm));
             }
             /*
@@ -59,6 +86,16 @@ pub(crate) async fn find_callback(path: String, callback_path: String) -> IsFind
                 param.push((callback_path_elm.replace(':', ""), path_split[i].to_owned()));
             }
         });
+    /*
+     * Wildcard
+     */
+    if let Some(name) = wildcard {
+        prepare_path.push_str(&format!("/{}", callback_path_split[fixed_len]));
+
+        if !name.is_empty() {
+            param.push((name, path_split[fixed_len..].join("/")));
+        }
+    }
     /*
      * Match current path with prepare path
      */
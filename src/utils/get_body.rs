@@ -0,0 +1,33 @@
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+/*
+ * Read Request Body up to Content Length
+ * Bytes past the Body stay in the Reader for the next Request
+ */
+pub(crate) async fn get_body<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    length: usize,
+    on_error: &ErrorHandler,
+) -> Vec<u8> {
+    let mut body: Vec<u8> = Vec::with_capacity(length);
+
+    while body.len() < length {
+        match reader.fill_buf().await {
+            Ok([]) => break,
+            Ok(x) => {
+                let n: usize = x.len().min(length - body.len());
+
+                body.extend_from_slice(&x[..n]);
+                reader.consume(n);
+            }
+            Err(e) => {
+                on_error(&ServerError::Read(e));
+                break;
+            }
+        }
+    }
+
+    body
+}
@@ -0,0 +1,87 @@
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use crate::structs::status_code::StatusCode;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, ErrorKind};
+
+/*
+ * Read Chunked Request Body (`Transfer-Encoding: chunked`)
+ * Decoded Bytes are counted against the Limit while read, so a Body without
+ * Content Length can't exceed it
+ * Errors with the Status to respond (413 over the Limit, 400 malformed)
+ */
+pub(crate) async fn get_chunked_body<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    limit: usize,
+    on_error: &ErrorHandler,
+) -> Result<Vec<u8>, StatusCode> {
+    let mut body: Vec<u8> = Vec::new();
+
+    loop {
+        let line: String = read_line(reader, on_error).await?;
+        let size: &str = line.split(';').next().unwrap_or_default().trim();
+
+        if size.is_empty() || !size.bytes().all(|x: u8| x.is_ascii_hexdigit()) {
+            return Err(StatusCode::BadRequest);
+        }
+
+        let size: usize =
+            usize::from_str_radix(size, 16).map_err(|_| StatusCode::PayloadTooLarge)?;
+        /*
+         * Last Chunk, Trailer Fields are skipped up to the empty Line
+         */
+        if size == 0 {
+            while !read_line(reader, on_error).await?.is_empty() {}
+
+            return Ok(body);
+        }
+
+        if size > limit - body.len() {
+            return Err(StatusCode::PayloadTooLarge);
+        }
+        /*
+         * Chunk Data followed by CRLF
+         */
+        let start: usize = body.len();
+        body.resize(start + size, 0);
+
+        let mut crlf: [u8; 2] = [0; 2];
+
+        let read = async {
+            reader.read_exact(&mut body[start..]).await?;
+            reader.read_exact(&mut crlf).await
+        };
+
+        match read.await {
+            Ok(_) if &crlf == b"\r\n" => {}
+            Ok(_) => return Err(StatusCode::BadRequest),
+            Err(e) => return Err(read_error(e, on_error)),
+        }
+    }
+}
+/*
+ * Read a Line of the Chunk Framing without CRLF, at most 4 KiB
+ */
+async fn read_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    on_error: &ErrorHandler,
+) -> Result<String, StatusCode> {
+    let mut line: Vec<u8> = Vec::new();
+
+    match (&mut *reader).take(4096).read_until(b'\n', &mut line).await {
+        Ok(_) => match line.strip_suffix(b"\r\n") {
+            Some(x) => Ok(String::from_utf8_lossy(x).to_string()),
+            None => Err(StatusCode::BadRequest),
+        },
+        Err(e) => Err(read_error(e, on_error)),
+    }
+}
+/*
+ * The Body ending early is malformed, other Read Errors are reported
+ */
+fn read_error(e: std::io::Error, on_error: &ErrorHandler) -> StatusCode {
+    if e.kind() != ErrorKind::UnexpectedEof {
+        on_error(&ServerError::Read(e));
+    }
+
+    StatusCode::BadRequest
+}
@@ -1,19 +1,84 @@
-use tokio::io::{AsyncReadExt, Error};
-use tokio::net::tcp::OwnedReadHalf;
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
 
-pub(crate) async fn get_header(mut reader: OwnedReadHalf) -> String {
-    let mut header: [u8; 512] = [0; 512];
+/*
+ * Read Header
+ * `too_large` when the Header exceeds the Size or Header Count Limit
+ */
+pub(crate) struct IsHeader {
+    pub(crate) header: String,
+    pub(crate) too_large: bool,
+}
+/*
+ * Read Request Header until the blank line
+ * Only the Header is consumed, Bytes after it stay in the Reader for the Body
+ * & next Requests
+ * Stops reading once the Header (Request Line included) exceeds `max_bytes`
+ * or has more than `max_count` Header Lines
+ */
+pub(crate) async fn get_header<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    max_bytes: usize,
+    max_count: usize,
+    on_error: &ErrorHandler,
+) -> IsHeader {
+    let mut header: Vec<u8> = Vec::new();
+
+    loop {
+        let x: &[u8] = match reader.fill_buf().await {
+            Ok([]) => break,
+            Ok(x) => x,
+            Err(e) => {
+                on_error(&ServerError::Read(e));
+
+                return IsHeader {
+                    header: String::new(),
+                    too_large: false,
+                };
+            }
+        };
+        /*
+         * End of Header, searched in the new Bytes & the last 3 before them
+         */
+        let read: usize = header.len();
+        let searched: usize = read.saturating_sub(3);
+
+        header.extend_from_slice(x);
 
-    let buffer_reader: Result<usize, Error> = reader.read(&mut header).await;
+        let end: Option<usize> = header[searched..]
+            .windows(4)
+            .position(|w: &[u8]| w == b"\r\n\r\n")
+            .map(|i: usize| searched + i);
 
-    if buffer_reader.is_err() {
-        println!(
-            "[Error] Error in Stream Buffer Reader:\n{}",
-            buffer_reader.err().unwrap()
-        );
+        let consumed: usize = match end {
+            Some(i) => {
+                header.truncate(i);
+                i + 4 - read
+            }
+            None => header.len() - read,
+        };
 
-        return String::new();
+        reader.consume(consumed);
+        /*
+         * Limits on the Header read so far
+         */
+        let lines: usize = header.windows(2).filter(|w| w == b"\r\n").count();
+
+        if header.len() > max_bytes || lines > max_count {
+            return IsHeader {
+                header: String::new(),
+                too_large: true,
+            };
+        }
+
+        if end.is_some() {
+            break;
+        }
     }
 
-    String::from_utf8_lossy(&header[..]).to_string()
+    IsHeader {
+        header: String::from_utf8_lossy(&header).to_string(),
+        too_large: false,
+    }
 }
@@ -1,164 +1,742 @@
 use crate::server::Server;
+use crate::structs::body_reader::{BodyChunks, BodyStream};
 use crate::structs::context::Context;
 use crate::structs::definition::{Callback, Returns, Tail};
-use crate::structs::request::Request;
-use crate::structs::response::Response;
+use crate::structs::method::Method;
+use crate::structs::payload::Payload;
+use crate::structs::pool::PoolGuard;
+use crate::structs::server_error::ServerError;
+use crate::structs::status_code::StatusCode;
+use crate::structs::timeout_writer::TimeoutWriter;
+use crate::structs::tree::Found;
 use crate::utils::find_callback::{find_callback, IsFind};
-use crate::utils::get_header::get_header;
-use crate::utils::parse_http_version::parse_http_version;
-use crate::utils::parse_method::parse_method;
-use crate::utils::parse_path::parse_path;
+use crate::utils::get_body::get_body;
+use crate::utils::get_chunked_body::get_chunked_body;
+use crate::utils::get_header::{get_header, IsHeader};
+use crate::utils::panic_message::panic_message;
+use crate::utils::pump_body::pump_body;
+use crate::utils::read_timeout::read_timeout;
 use crate::utils::response_payload::response_payload;
 use crate::utils::response_payload_empty::response_payload_empty;
+use crate::utils::response_payload_status::response_payload_status;
+use crate::utils::valid_request::valid_request;
+#[cfg(feature = "websocket")]
+use crate::utils::websocket_session::websocket_session;
+use bytes::Bytes;
+use futures::FutureExt;
 use std::net::SocketAddr;
-use std::sync::Arc;
-use tokio::join;
-use tokio::net::TcpStream;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex};
+use tokio::io::{
+    split, AsyncBufRead, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, Error, ReadHalf,
+    WriteHalf,
+};
+use tokio::sync::mpsc::{channel, Sender};
+use tokio::sync::watch::Receiver;
+use tokio::{pin, select};
 
 /*
  * Handler
+ * Generic over the Stream (TCP, TLS, in-memory, ...)
  */
-pub(crate) async fn handler(server: Server, address: SocketAddr, stream: TcpStream) {
-    let (reader, writer) = stream.into_split();
+pub(crate) async fn handler<S>(
+    server: &Server,
+    address: SocketAddr,
+    stream: S,
+    shutdown: Receiver<bool>,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, writer): (ReadHalf<S>, WriteHalf<S>) = split(stream);
+    /*
+     * One buffered Reader per Connection, Bytes read ahead of a Request stay
+     * in it for the next Request on a kept-alive Connection
+     */
+    let mut reader: BufReader<ReadHalf<S>> = BufReader::with_capacity(8 * 1024, reader);
+    /*
+     * Stalled Writes fail after the Write Timeout
+     */
+    let mut writer: TimeoutWriter<WriteHalf<S>> = TimeoutWriter::new(writer, server.write_timeout);
 
-    let header: String = get_header(reader).await;
+    connection(server, address, &mut reader, &mut writer, shutdown).await;
+    /*
+     * Close the Stream (e.g. TLS close_notify)
+     */
+    writer.shutdown().await.ok();
+}
+/*
+ * Serve Requests on the Connection until it is not kept alive
+ */
+async fn connection<R, W>(
+    server: &Server,
+    address: SocketAddr,
+    reader: &mut R,
+    writer: &mut W,
+    mut shutdown: Receiver<bool>,
+) where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut header: String = match read_timeout(
+        server.read_timeout,
+        get_header(
+            reader,
+            server.max_header_bytes,
+            server.max_header_count,
+            &server.on_error,
+        ),
+    )
+    .await
+    {
+        Some(x) if x.too_large => {
+            response_payload_status(writer, 431, &server.on_error).await;
+            return;
+        }
+        Some(x) => x.header,
+        None => {
+            response_payload_status(writer, 408, &server.on_error).await;
+            return;
+        }
+    };
 
     if header.is_empty() {
-        response_payload_empty(writer).await;
+        response_payload_empty(writer, &server.on_error).await;
         return;
     }
+
+    loop {
+        let keep_alive: bool = request(server, address, reader, writer, header, &shutdown).await;
+
+        if !keep_alive {
+            return;
+        }
+        /*
+         * Wait for the next Request
+         * Idle Connections close on Keep-Alive Timeout or Shutdown
+         */
+        let next: Option<IsHeader> = select! {
+            x = read_timeout(
+                Some(server.keep_alive_timeout),
+                get_header(
+                    reader,
+                    server.max_header_bytes,
+                    server.max_header_count,
+                    &server.on_error,
+                ),
+            ) => x,
+            _ = shutdown.changed() => return,
+        };
+
+        header = match next {
+            Some(x) if x.too_large => {
+                response_payload_status(writer, 431, &server.on_error).await;
+                return;
+            }
+            Some(x) if !x.header.is_empty() => x.header,
+            _ => return,
+        };
+    }
+}
+/*
+ * Read, Dispatch & Write a single Request
+ * Returns whether the Connection is kept alive
+ */
+async fn request<R, W>(
+    server: &Server,
+    address: SocketAddr,
+    reader: &mut R,
+    writer: &mut W,
+    header: String,
+    shutdown: &Receiver<bool>,
+) -> bool
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
     /*
-     * Incoming
+     * Malformed Request responds 400 & closes the Connection
      */
-    let ((url, path, query), method, http_version) = join!(
-        parse_path(header.clone()),
-        parse_method(header.clone()),
-        parse_http_version(header.clone())
-    );
+    if !valid_request(&header) {
+        response_payload_status(writer, 400, &server.on_error).await;
+        return false;
+    }
     /*
      * Context
      */
-    let mut context: Context = Context {
-        next: true,
-        state: Vec::new(),
-        request: Request {
-            address: address.to_string(),
-            header,
-            header_store: Vec::new(),
-            param_store: Vec::new(),
-            query_store: Vec::new(),
-            method: method.clone(),
-            url,
-            path: path.clone(),
-            query,
-            http_version,
-        },
-        response: Response {
-            header: Vec::new(),
-            body: String::new(),
-            status: 200,
-            content_type: "text/html".to_owned(),
-        },
+    let mut context: Context = Context::new(address, header, server.states.clone()).await;
+
+    let method: String = context.request.method.clone();
+    let path: String = context.request.raw_path.clone();
+    let http_version: f64 = context.request.http_version;
+    /*
+     * Method not Routable
+     * CONNECT & TRACE respond 501, unknown Methods 400, unless a Route is
+     * registered for the Method
+     */
+    let status: Option<StatusCode> = match Method::from(method.as_str()) {
+        x if x.is_routable() => None,
+        _ if server
+            .adds
+            .iter()
+            .any(|(x, _, _)| x.eq_ignore_ascii_case(&method)) =>
+        {
+            None
+        }
+        Method::Other(_) => Some(StatusCode::BadRequest),
+        _ => Some(StatusCode::NotImplemented),
     };
+
+    if let Some(x) = status {
+        context.response.status = x.as_u16() as usize;
+        context.response.body = x.reason().to_owned();
+        context.response.set_header("Connection", "close").await;
+
+        response_payload(writer, context, http_version, server).await;
+        return false;
+    }
     /*
-     * Find & Callback
+     * Body
      */
-    let mut tails: Vec<Tail> = Vec::new();
+    let content_length: usize = context
+        .request
+        .header("content-length")
+        .await
+        .and_then(|x: String| x.parse().ok())
+        .unwrap_or(0);
+    /*
+     * Body exceeds Limit
+     * Respond without reading it & close the Connection
+     */
+    if content_length > server.max_body_size {
+        context.response.status = 413;
+        context.response.body = "Payload Too Large".to_owned();
+        context.response.set_header("Connection", "close").await;
 
-    let adds: Vec<(String, String, Vec<Arc<Callback>>)> = server.adds;
+        response_payload(writer, context, http_version, server).await;
+        return false;
+    }
+    /*
+     * Chunked Body has no Content Length, its Bytes are counted while read
+     * Other Transfer Codings aren't supported, with a Content Length the
+     * Framing is ambiguous
+     */
+    let chunked: bool = match context.request.header("transfer-encoding").await {
+        Some(x) => {
+            let last_coding: String = x
+                .rsplit(',')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase();
 
-    for add in adds.iter() {
-        if !context.next {
-            break;
+            if last_coding != "chunked" {
+                response_payload_status(writer, 501, &server.on_error).await;
+                return false;
+            }
+
+            if context.request.header("content-length").await.is_some() {
+                response_payload_status(writer, 400, &server.on_error).await;
+                return false;
+            }
+
+            true
+        }
+        None => false,
+    };
+    /*
+     * Expect 100 Continue
+     * The Client waits for the interim Response before sending the Body
+     */
+    let expect: Option<String> = context.request.header("expect").await;
+
+    if http_version >= 1.1
+        && (content_length > 0 || chunked)
+        && expect.is_some_and(|x: String| x.trim().eq_ignore_ascii_case("100-continue"))
+    {
+        if let Err(e) = writer.write_all(b"HTTP/1.1 100 Continue\r\n\r\n").await {
+            (server.on_error)(&ServerError::write(e));
+            return false;
         }
+    }
+    /*
+     * Streamed Body is read while dispatching, buffered Body before
+     */
+    let mut body_complete: bool = true;
+
+    let dispatched: Option<Context> = if server.stream_body && content_length > 0 {
+        let (sender, receiver): (Sender<Result<Vec<u8>, Error>>, BodyChunks) = channel(8);
+
+        context.request.body_stream = Some(BodyStream(Arc::new(Mutex::new(Some(receiver)))));
 
-        let method_cp: String = if add.0 == "*" {
-            method.clone().to_lowercase()
-        } else {
-            add.0.to_lowercase()
+        let pump = pump_body(
+            reader,
+            content_length,
+            server.read_timeout,
+            sender,
+            &server.on_error,
+        );
+        let dispatch = dispatch(server, context, method, path, http_version, writer);
+
+        pin!(pump);
+        pin!(dispatch);
+
+        let mut pumped: bool = false;
+        body_complete = false;
+
+        loop {
+            select! {
+                x = &mut dispatch => break x,
+                x = &mut pump, if !pumped => {
+                    pumped = true;
+                    body_complete = x;
+                }
+            }
+        }
+    } else {
+        let body = async {
+            match chunked {
+                true => get_chunked_body(reader, server.max_body_size, &server.on_error).await,
+                false => Ok(get_body(reader, content_length, &server.on_error).await),
+            }
         };
 
-        let path_cp: String = if add.1 == "*" {
-            path.clone().to_lowercase()
-        } else {
-            add.1.to_lowercase()
+        context.request.body = match read_timeout(server.read_timeout, body).await {
+            Some(Ok(x)) => Bytes::from(x),
+            Some(Err(x)) => {
+                context.response.status = x.as_u16() as usize;
+                context.response.body = x.reason().to_owned();
+                context.response.set_header("Connection", "close").await;
+
+                response_payload(writer, context, http_version, server).await;
+                return false;
+            }
+            None => {
+                context.response.status = 408;
+                context.response.body = "Request Timeout".to_owned();
+                context.response.set_header("Connection", "close").await;
+
+                response_payload(writer, context, http_version, server).await;
+                return false;
+            }
         };
 
-        let callback_cp: Vec<Arc<Callback>> = add.2.to_owned();
+        dispatch(server, context, method, path, http_version, writer).await
+    };
 
-        if method_cp != method.to_lowercase() {
-            continue;
+    let mut context: Context = match dispatched {
+        Some(x) => x,
+        None => return false,
+    };
+    /*
+     * WebSocket Upgrade
+     * The Connection belongs to the WebSocket after `101 Switching Protocols`
+     */
+    #[cfg(feature = "websocket")]
+    if let Some(Payload::WebSocket(x)) = &context.response.payload {
+        let func = x.take();
+        context.response.payload = None;
+
+        if let (Some(func), 101) = (func, context.response.status) {
+            if response_payload(writer, context, http_version, server).await {
+                websocket_session(
+                    reader,
+                    writer,
+                    func,
+                    server.max_body_size,
+                    shutdown,
+                    &server.on_error,
+                )
+                .await;
+            }
+            return false;
         }
-        /*
-         * Static
-         */
-        if path_cp == path.to_lowercase() {
-            for callback in callback_cp.clone() {
-                context.next = false;
+    }
+    /*
+     * Keep-Alive
+     * HTTP/1.1 keeps the Connection unless `close`, HTTP/1.0 only with `keep-alive`
+     * Request Bodies without Content Length can't be framed & close it, so do
+     * streamed Bodies not read to the end
+     */
+    let connection: String = context
+        .request
+        .header("connection")
+        .await
+        .unwrap_or_default()
+        .to_lowercase();
 
-                context.request.param_store = Vec::new();
+    let mut keep_alive: bool = if http_version >= 1.1 {
+        !connection.contains("close")
+    } else {
+        connection.contains("keep-alive")
+    };
 
-                let callback_returns: Returns = (callback)(context).await;
+    /*
+     * Streamed Bodies of HTTP/1.0 end by closing the Connection
+     */
+    let stream: bool = matches!(context.response.payload, Some(Payload::Stream(_)));
 
-                context = callback_returns.0;
+    if !server.keep_alive
+        || *shutdown.borrow()
+        || context.request.header("transfer-encoding").await.is_some()
+        || !body_complete
+        || (stream && http_version < 1.1)
+    {
+        keep_alive = false;
+    }
 
-                if let Some(x) = callback_returns.1 {
-                    tails.push(x);
-                }
+    if let Some(x) = context.response.get_header("connection").await {
+        if x.to_lowercase().contains("close") {
+            keep_alive = false;
+        }
+    }
 
-                if !context.next {
-                    break;
-                }
+    if !keep_alive {
+        context.response.set_header("Connection", "close").await;
+    } else if http_version < 1.1 {
+        context
+            .response
+            .set_header("Connection", "keep-alive")
+            .await;
+    }
+
+    response_payload(writer, context, http_version, server).await && keep_alive
+}
+/*
+ * Run Middlewares, Routes & Tails on a Worker
+ * Returns None when a Response was already written (503 or 500)
+ */
+async fn dispatch<W: AsyncWrite + Unpin>(
+    server: &Server,
+    mut context: Context,
+    method: String,
+    path: String,
+    http_version: f64,
+    writer: &mut W,
+) -> Option<Context> {
+    /*
+     * Wait for a free Worker
+     * Full Queue with `Reject` Backpressure responds 503
+     */
+    let worker: PoolGuard = match server.pool.acquire().await {
+        Some(x) => x,
+        None => {
+            context.response.status = 503;
+            context.response.body = "Service Unavailable".to_owned();
+            context.response.set_header("Connection", "close").await;
+
+            response_payload(writer, context, http_version, server).await;
+            return None;
+        }
+    };
+    /*
+     * Find & Callback
+     */
+    let mut tails: Vec<Tail> = Vec::new();
+
+    let adds: &[(String, String, Vec<Arc<Callback>>)] = &server.adds;
+    /*
+     * Method Override
+     * POST tunnels PUT, PATCH or DELETE through `X-HTTP-Method-Override` or
+     * a `_method` Form Field
+     */
+    let method: String = if server.method_override && method.to_uppercase() == "POST" {
+        let method_override: Option<String> =
+            match context.request.header("x-http-method-override").await {
+                Some(x) => Some(x),
+                None => context.request.form_field("_method").await.ok().flatten(),
+            };
+
+        match method_override.map(|x: String| x.trim().to_uppercase()) {
+            Some(x) if ["PUT", "PATCH", "DELETE"].contains(&x.as_str()) => {
+                context.request.method = x.clone();
+                x
             }
+            _ => method,
+        }
+    } else {
+        method
+    };
+    /*
+     * Context before the Functions run, for the 500 Catcher & the 504 Response
+     * of a timed out Function
+     */
+    let pristine: Option<Context> = if server.internal_error.is_some()
+        || server.catchers.contains_key(&500)
+        || server.handler_timeout.is_some()
+    {
+        Some(context.clone())
+    } else {
+        None
+    };
+    /*
+     * HEAD is answered by GET Routes unless a HEAD Route is registered
+     */
+    let method: String = if method.to_uppercase() == "HEAD"
+        && !server.tree.methods(&path).contains(&"HEAD".to_owned())
+    {
+        "GET".to_owned()
+    } else {
+        method
+    };
+    /*
+     * Candidate Routes
+     * Matched Routes from Segment Trie & Middlewares in Registration Order
+     */
+    let routes: Vec<Found> = server.tree.find(&method, &path);
+    /*
+     * Matched Route Pattern (first registered), visible to Middlewares & Tails
+     */
+    context.matched_route = routes
+        .iter()
+        .map(|(i, _)| *i)
+        .min()
+        .map(|i: usize| adds[i].1.to_owned());
+
+    let mut candidates: Vec<usize> = routes
+        .iter()
+        .map(|(i, _)| *i)
+        .chain(server.middlewares.iter().copied())
+        .collect();
+    candidates.sort_unstable();
+
+    for index in candidates {
+        if !context.next {
+            break;
+        }
+
+        let add: &(String, String, Vec<Arc<Callback>>) = &adds[index];
 
+        if add.0 != "*" && add.0.to_lowercase() != method.to_lowercase() {
             continue;
         }
         /*
-         * Dynamic
+         * Parameters
          */
-        let find_callback: IsFind = find_callback(path.to_owned(), path_cp).await;
+        let param: Vec<(String, String)> = match routes.iter().find(|(i, _)| *i == index) {
+            Some((_, x)) => x.to_owned(),
+            None if add.1 == "*" => Vec::new(),
+            None => {
+                let case_insensitive: bool = server.tree.case_insensitive;
 
-        if find_callback.find {
-            for callback in callback_cp.clone() {
-                context.next = false;
+                let callback_path: String = if case_insensitive {
+                    add.1.to_lowercase()
+                } else {
+                    add.1.to_owned()
+                };
 
-                context.request.param_store = find_callback.param.to_owned();
+                let find_callback: IsFind =
+                    find_callback(path.to_owned(), callback_path, case_insensitive).await;
 
-                let callback_returns: Returns = (callback)(context).await;
+                if !find_callback.find {
+                    continue;
+                }
+
+                find_callback.param
+            }
+        };
+
+        for callback in add.2.iter() {
+            context.next = false;
+
+            context.request.param_store = param.to_owned();
 
-                context = callback_returns.0;
+            let callback_returns: Returns = match read_timeout(
+                server.handler_timeout,
+                AssertUnwindSafe((callback)(context)).catch_unwind(),
+            )
+            .await
+            {
+                Some(Ok(x)) => x,
+                /*
+                 * Panicking Function responds 500 & closes the Connection
+                 */
+                Some(Err(e)) => {
+                    (server.on_error)(&ServerError::Panic(panic_message(&*e)));
 
-                if let Some(x) = callback_returns.1 {
-                    tails.push(x);
+                    internal_error(server, pristine, http_version, writer).await;
+                    return None;
                 }
+                /*
+                 * Function ran too long, it is dropped & the Request responds 504
+                 */
+                None => {
+                    let mut x: Context = pristine
+                        .clone()
+                        .expect("[Error] Handler Timeout without Context");
 
-                if !context.next {
-                    break;
+                    (server.on_error)(&ServerError::Timeout(format!(
+                        "{} {}",
+                        x.request.method, x.request.path
+                    )));
+
+                    x.next = false;
+                    x.response.status = 504;
+                    x.response.body = "Gateway Timeout".to_owned();
+                    x.response.content_type = "text/plain".to_owned();
+                    x.response.payload = None;
+
+                    (x, None)
                 }
+            };
+
+            context = callback_returns.0;
+
+            if let Some(x) = callback_returns.1 {
+                tails.push(x);
+            }
+
+            if !context.next {
+                break;
             }
         }
     }
     /*
      * Route Not Found
      */
+    let mut unmatched: bool = false;
+
     if context.next {
+        let mut allow: Vec<String> = server.tree.methods(&path);
+
+        if allow.contains(&"GET".to_owned()) && !allow.contains(&"HEAD".to_owned()) {
+            allow.push("HEAD".to_owned());
+        }
+        /*
+         * Automatic OPTIONS
+         */
+        if server.auto_options && !allow.is_empty() && method.to_uppercase() == "OPTIONS" {
+            if !allow.contains(&"OPTIONS".to_owned()) {
+                allow.push("OPTIONS".to_owned());
+            }
+
+            context.response.status = 204;
+            context.response.body = String::new();
+            context
+                .response
+                .set_header("Allow", &allow.join(", "))
+                .await;
+        }
+        /*
+         * Path registered under other Methods
+         */
+        else if !allow.is_empty() && !allow.contains(&method.to_uppercase()) {
+            context.response.status = 405;
+            context.response.body = "Method Not Allowed".to_owned();
+            context
+                .response
+                .set_header("Allow", &allow.join(", "))
+                .await;
+        }
         /*
          * Default Status & Body
          */
-        context.response.status = 404;
-        context.response.body = "Not Found".to_owned();
+        else {
+            context.response.status = 404;
+            context.response.body = "Not Found".to_owned();
+            context.response.content_type = "text/plain".to_owned();
+
+            unmatched = true;
+        }
+    }
+    /*
+     * Error Catcher for the Response Status
+     * `not_found` is the Fallback for unmatched Routes
+     */
+    if context.response.status >= 400 {
+        let catcher: Option<&Arc<Callback>> = match server.catchers.get(&context.response.status) {
+            Some(x) => Some(x),
+            None if unmatched => server.not_found.as_ref(),
+            None => None,
+        };
+
+        if let Some(func) = catcher {
+            context.next = false;
+
+            let callback_returns: Returns =
+                match AssertUnwindSafe((func)(context)).catch_unwind().await {
+                    Ok(x) => x,
+                    Err(e) => {
+                        (server.on_error)(&ServerError::Panic(panic_message(&*e)));
+
+                        internal_error(server, pristine, http_version, writer).await;
+                        return None;
+                    }
+                };
+
+            context = callback_returns.0;
+
+            if let Some(x) = callback_returns.1 {
+                tails.push(x);
+            }
+        }
     }
     /*
      * Tail
+     * Runs in reverse order also after a short-circuit (`next` left false)
+     * A Tail sets `next` to false to skip the remaining Tails
      */
     if !tails.is_empty() {
+        context.next = true;
+
         for i in tails.iter().rev() {
-            context = (i)(context).await;
+            context = match AssertUnwindSafe((i)(context)).catch_unwind().await {
+                Ok(x) => x,
+                Err(e) => {
+                    (server.on_error)(&ServerError::Panic(panic_message(&*e)));
+
+                    internal_error(server, pristine, http_version, writer).await;
+                    return None;
+                }
+            };
             if !context.next {
                 break;
             }
         }
     }
 
-    response_payload(writer, context, http_version).await;
+    drop(worker);
+
+    Some(context)
+}
+/*
+ * Respond 500 after a Panic & close the Connection
+ * The 500 Catcher or `Server::internal_error` builds the Response on the
+ * Context as it was before the Functions ran, a bare 500 is written without
+ * them or if it panics
+ */
+async fn internal_error<W: AsyncWrite + Unpin>(
+    server: &Server,
+    pristine: Option<Context>,
+    http_version: f64,
+    writer: &mut W,
+) {
+    let catcher: Option<&Arc<Callback>> =
+        server.catchers.get(&500).or(server.internal_error.as_ref());
+
+    let (func, mut context): (&Arc<Callback>, Context) = match (catcher, pristine) {
+        (Some(func), Some(context)) => (func, context),
+        _ => {
+            response_payload_status(writer, 500, &server.on_error).await;
+            return;
+        }
+    };
+
+    context.next = false;
+    context.response.status = 500;
+    context.response.body = "Internal Server Error".to_owned();
+    context.response.content_type = "text/plain".to_owned();
+
+    let mut context: Context = match AssertUnwindSafe((func)(context)).catch_unwind().await {
+        Ok(x) => x.0,
+        Err(e) => {
+            (server.on_error)(&ServerError::Panic(panic_message(&*e)));
+
+            response_payload_status(writer, 500, &server.on_error).await;
+            return;
+        }
+    };
+
+    context.response.set_header("Connection", "close").await;
+
+    response_payload(writer, context, http_version, server).await;
 }
@@ -0,0 +1,15 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/*
+ * HMAC-SHA256 (RFC 2104, FIPS 180-4) for signed Cookies
+ */
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    /*
+     * HMAC takes Keys of any Length
+     */
+    let mut mac: Hmac<Sha256> = Hmac::new_from_slice(key).expect("HMAC takes any key length");
+
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
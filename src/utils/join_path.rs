@@ -0,0 +1,23 @@
+/*
+ * Join Mount Prefix with Route Path
+ */
+pub(crate) fn join_path(prefix: &str, path: &str) -> String {
+    let prefix: &str = prefix.trim_end_matches('/');
+    /*
+     * Any Path (Middleware) is scoped under the Prefix
+     */
+    if path == "*" {
+        return format!("{}/*", prefix);
+    }
+
+    let path: &str = path.trim_start_matches('/');
+
+    if path.is_empty() {
+        if prefix.is_empty() {
+            return "/".to_owned();
+        }
+        return prefix.to_owned();
+    }
+
+    format!("{}/{}", prefix, path)
+}
@@ -0,0 +1,10 @@
+/*
+ * Debug Message through the `log` Crate, dropped without the `log` Feature
+ */
+pub(crate) fn log_debug(message: &str) {
+    #[cfg(feature = "log")]
+    log::debug!("{}", message);
+
+    #[cfg(not(feature = "log"))]
+    let _ = message;
+}
@@ -0,0 +1,10 @@
+/*
+ * Error Message through the `log` Crate, or printed without the `log` Feature
+ */
+pub(crate) fn log_error(message: &str) {
+    #[cfg(feature = "log")]
+    log::error!("{}", message);
+
+    #[cfg(not(feature = "log"))]
+    println!("[Error] {}", message);
+}
@@ -0,0 +1,10 @@
+/*
+ * Info Message through the `log` Crate, or printed without the `log` Feature
+ */
+pub(crate) fn log_info(message: &str) {
+    #[cfg(feature = "log")]
+    log::info!("{}", message);
+
+    #[cfg(not(feature = "log"))]
+    println!("{}", message);
+}
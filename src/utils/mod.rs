@@ -1,12 +1,51 @@
+pub(crate) mod base64_decode;
+pub(crate) mod bind_listener;
+pub(crate) mod check_path;
+pub(crate) mod constant_time_eq;
+pub(crate) mod cpus;
 pub(crate) mod del_vec;
 pub(crate) mod find_callback;
+pub(crate) mod get_body;
+pub(crate) mod get_chunked_body;
 pub(crate) mod get_header;
 pub(crate) mod get_vec;
 pub(crate) mod handler;
+pub(crate) mod hmac_sha256;
+pub(crate) mod http_date;
+pub(crate) mod join_path;
+pub(crate) mod log_debug;
+pub(crate) mod log_error;
+pub(crate) mod log_info;
+pub(crate) mod mime_type;
+pub(crate) mod not_modified;
+pub(crate) mod panic_message;
+pub(crate) mod parse_accept;
+pub(crate) mod parse_cookie;
+pub(crate) mod parse_header;
+pub(crate) mod parse_http_date;
 pub(crate) mod parse_http_version;
 pub(crate) mod parse_method;
+pub(crate) mod parse_multipart;
 pub(crate) mod parse_path;
+pub(crate) mod parse_query;
+pub(crate) mod parse_range;
+pub(crate) mod percent_decode;
+pub(crate) mod percent_encode;
+pub(crate) mod pump_body;
+pub(crate) mod random_token;
+pub(crate) mod read_multipart;
+pub(crate) mod read_timeout;
 pub(crate) mod response_payload;
 pub(crate) mod response_payload_empty;
+pub(crate) mod response_payload_status;
 pub(crate) mod set_vec;
+pub(crate) mod static_file;
 pub(crate) mod status_string;
+#[cfg(feature = "tls")]
+pub(crate) mod tls_acceptor;
+pub(crate) mod uuid_v4;
+pub(crate) mod valid_request;
+#[cfg(feature = "websocket")]
+pub(crate) mod websocket_frame;
+#[cfg(feature = "websocket")]
+pub(crate) mod websocket_session;
@@ -0,0 +1,38 @@
+use crate::utils::parse_http_date::parse_http_date;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/*
+ * Whether the Client's cached Copy is current (respond 304)
+ * `If-None-Match` (weak comparison) takes precedence over `If-Modified-Since`
+ */
+pub(crate) fn not_modified(
+    if_none_match: Option<String>,
+    if_modified_since: Option<String>,
+    etag: &str,
+    modified: SystemTime,
+) -> bool {
+    if let Some(x) = if_none_match {
+        let etag: &str = etag.trim_start_matches("W/");
+
+        return x
+            .split(',')
+            .map(|x: &str| x.trim())
+            .any(|x: &str| x == "*" || x.trim_start_matches("W/") == etag);
+    }
+
+    match if_modified_since.as_deref().and_then(parse_http_date) {
+        /*
+         * HTTP Dates have Second Precision
+         */
+        Some(since) => {
+            let secs = |x: SystemTime| -> u64 {
+                x.duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0)
+            };
+
+            secs(modified) <= secs(since)
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,16 @@
+use std::any::Any;
+
+/*
+ * Get Message from a Panic Payload
+ */
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(x) = payload.downcast_ref::<&str>() {
+        return x.to_string();
+    }
+
+    if let Some(x) = payload.downcast_ref::<String>() {
+        return x.to_owned();
+    }
+
+    "Unknown Panic".to_owned()
+}
@@ -0,0 +1,69 @@
+/*
+ * Parse Accept Header (`text/html, application/json;q=0.8`) into Media Ranges & Quality
+ * Malformed Entries are skipped
+ */
+pub(crate) async fn parse_accept(accept: String) -> Vec<(String, f32)> {
+    accept
+        .split(',')
+        .filter_map(|x: &str| {
+            let mut params = x.split(';');
+
+            let range: String = params.next()?.trim().to_lowercase();
+            let (kind, subtype) = range.split_once('/')?;
+
+            if kind.is_empty() || subtype.is_empty() || (kind == "*" && subtype != "*") {
+                return None;
+            }
+
+            let mut quality: f32 = 1.0;
+
+            for param in params {
+                if let Some((k, v)) = param.split_once('=') {
+                    if k.trim().eq_ignore_ascii_case("q") {
+                        quality = v
+                            .trim()
+                            .parse()
+                            .ok()
+                            .filter(|x: &f32| (0.0..=1.0).contains(x))?;
+                    }
+                }
+            }
+
+            Some((range, quality))
+        })
+        .collect()
+}
+/*
+ * Quality of a Content Type by the most specific matching Media Range
+ * 0 when not acceptable
+ */
+pub(crate) async fn accept_quality(accept: &[(String, f32)], content_type: &str) -> f32 {
+    let content_type: String = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    let kind: &str = content_type.split('/').next().unwrap_or_default();
+
+    let mut best: Option<(u8, f32)> = None;
+
+    for (range, quality) in accept {
+        let specificity: u8 = if *range == content_type {
+            2
+        } else if range.strip_suffix("/*") == Some(kind) {
+            1
+        } else if range == "*/*" {
+            0
+        } else {
+            continue;
+        };
+
+        if best.is_none_or(|(s, _)| specificity > s) {
+            best = Some((specificity, *quality));
+        }
+    }
+
+    best.map(|(_, q)| q).unwrap_or(0.0)
+}
@@ -0,0 +1,11 @@
+/*
+ * Parse Cookie Header (`a=1; b=2`) into Key Value pairs
+ */
+pub(crate) async fn parse_cookie(cookie: String) -> Vec<(String, String)> {
+    cookie
+        .split(';')
+        .filter_map(|x: &str| x.split_once('='))
+        .map(|(k, v)| (k.trim().to_owned(), v.trim().trim_matches('"').to_owned()))
+        .filter(|(k, _)| !k.is_empty())
+        .collect()
+}
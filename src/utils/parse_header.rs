@@ -0,0 +1,54 @@
+use std::ops::Range;
+
+/*
+ * Position of a Header Name & Value in the Request Header
+ */
+pub(crate) type HeaderRange = (Range<usize>, Range<usize>);
+/*
+ * Parse Request Header Lines (`Key: Value`) into Name Value Positions
+ * Nothing is copied, Values are sliced out of the Header when requested
+ * Header Names are matched case-insensitively (RFC 7230)
+ */
+pub(crate) async fn parse_header(header: &str) -> Vec<HeaderRange> {
+    let mut headers: Vec<HeaderRange> = Vec::new();
+    let mut offset: usize = 0;
+
+    for (i, ln) in header.split_inclusive('\n').enumerate() {
+        let start: usize = offset;
+        offset += ln.len();
+        /*
+         * Skip Request Line
+         */
+        if i == 0 {
+            continue;
+        }
+
+        let ln: &str = ln.trim_end_matches('\n').trim_end_matches('\r');
+
+        let colon: usize = match ln.find(':') {
+            Some(x) => x,
+            None => continue,
+        };
+        /*
+         * Key must not contain Whitespace
+         */
+        let k: Range<usize> = trimmed(start, &ln[..colon]);
+        let key: &str = &header[k.clone()];
+
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            continue;
+        }
+
+        headers.push((k, trimmed(start + colon + 1, &ln[colon + 1..])));
+    }
+
+    headers
+}
+/*
+ * Position of the trimmed Text starting at `start`
+ */
+fn trimmed(start: usize, text: &str) -> Range<usize> {
+    let start: usize = start + text.len() - text.trim_start().len();
+
+    start..start + text.trim().len()
+}
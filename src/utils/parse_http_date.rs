@@ -0,0 +1,48 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/*
+ * Parse HTTP Date (IMF-fixdate) into Time
+ * e.g. `Sun, 06 Nov 1994 08:49:37 GMT`, None for other Formats
+ */
+pub(crate) fn parse_http_date(value: &str) -> Option<SystemTime> {
+    let parts: Vec<&str> = value.split_whitespace().collect();
+
+    let (day, month, year, time) = match parts[..] {
+        [_, day, month, year, time, "GMT"] => (day, month, year, time),
+        _ => return None,
+    };
+
+    let months: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let day: i64 = day.parse().ok()?;
+    let month: i64 = months.iter().position(|x: &&str| *x == month)? as i64 + 1;
+    let year: i64 = year.parse().ok()?;
+
+    let time: Vec<u64> = time
+        .split(':')
+        .map(|x: &str| x.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+
+    let (hour, minute, second) = match time[..] {
+        [h, m, s] if h < 24 && m < 60 && s < 61 => (h, m, s),
+        _ => return None,
+    };
+    /*
+     * Days from Civil (Howard Hinnant)
+     */
+    let y: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = y.div_euclid(400);
+    let yoe: i64 = y - era * 400;
+    let mp: i64 = if month > 2 { month - 3 } else { month + 9 };
+    let doy: i64 = (153 * mp + 2) / 5 + day - 1;
+    let doe: i64 = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days: i64 = era * 146097 + doe - 719468;
+
+    if days < 0 || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    Some(UNIX_EPOCH + Duration::from_secs(days as u64 * 86400 + hour * 3600 + minute * 60 + second))
+}
@@ -1,4 +1,4 @@
-pub(crate) async fn parse_method(header: String) -> String {
+pub(crate) async fn parse_method(header: &str) -> String {
     header
         .lines()
         .next()
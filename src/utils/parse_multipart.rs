@@ -0,0 +1,140 @@
+use crate::structs::multipart::{Multipart, Part};
+use crate::structs::multipart_error::MultipartError;
+use crate::utils::get_vec::get_vec;
+
+/*
+ * Parse Multipart Body split on `--{boundary}`
+ */
+pub(crate) async fn parse_multipart(
+    body: &[u8],
+    boundary: &str,
+) -> Result<Multipart, MultipartError> {
+    let delimiter: Vec<u8> = format!("--{}", boundary).into_bytes();
+    let next_delimiter: Vec<u8> = format!("\r\n--{}", boundary).into_bytes();
+    /*
+     * Preamble before the first Delimiter is ignored
+     */
+    let mut rest: &[u8] = match find(body, &delimiter) {
+        Some(i) => &body[i + delimiter.len()..],
+        None => return Err(MultipartError::Malformed("boundary not found".to_owned())),
+    };
+
+    let mut parts: Vec<Part> = Vec::new();
+
+    loop {
+        /*
+         * Close Delimiter
+         */
+        if rest.starts_with(b"--") {
+            return Ok(Multipart { parts });
+        }
+
+        rest = match rest.strip_prefix(b"\r\n") {
+            Some(x) => x,
+            None => return Err(MultipartError::Malformed("invalid delimiter".to_owned())),
+        };
+
+        let end: usize = match find(rest, &next_delimiter) {
+            Some(x) => x,
+            None => return Err(MultipartError::Malformed("unterminated part".to_owned())),
+        };
+
+        parts.push(parse_part(&rest[..end]).await?);
+
+        rest = &rest[end + next_delimiter.len()..];
+    }
+}
+/*
+ * Parse Part Headers & Content
+ */
+async fn parse_part(part: &[u8]) -> Result<Part, MultipartError> {
+    let (head, data): (&[u8], &[u8]) = if part.starts_with(b"\r\n") {
+        (&[], &part[2..])
+    } else {
+        match find(part, b"\r\n\r\n") {
+            Some(i) => (&part[..i], &part[i + 4..]),
+            None => return Err(MultipartError::Malformed("part without headers".to_owned())),
+        }
+    };
+
+    let mut part: Part = parse_part_head(head).await?;
+    part.data = data.to_vec();
+
+    Ok(part)
+}
+/*
+ * Parse Part Headers (Content is empty)
+ */
+pub(crate) async fn parse_part_head(head: &[u8]) -> Result<Part, MultipartError> {
+    let headers: Vec<(String, String)> = String::from_utf8_lossy(head)
+        .split("\r\n")
+        .filter_map(|x: &str| {
+            let (k, v) = x.split_once(':')?;
+            Some((k.trim().to_owned(), v.trim().to_owned()))
+        })
+        .collect();
+
+    let disposition: String = get_vec(&headers, "content-disposition".to_owned())
+        .await
+        .unwrap_or_default();
+    let params: Vec<(String, String)> = header_params(&disposition);
+
+    let name: String = match params.iter().find(|(k, _)| k == "name") {
+        Some((_, v)) => v.to_owned(),
+        None => return Err(MultipartError::Malformed("part without name".to_owned())),
+    };
+
+    Ok(Part {
+        name,
+        filename: params
+            .iter()
+            .find(|(k, _)| k == "filename")
+            .map(|(_, v)| v.to_owned()),
+        content_type: get_vec(&headers, "content-type".to_owned()).await,
+        headers,
+        ..Default::default()
+    })
+}
+/*
+ * Header Parameters (`form-data; name="a"; filename="b.txt"`)
+ * Quoted Values may contain `;`
+ */
+pub(crate) fn header_params(value: &str) -> Vec<(String, String)> {
+    let mut params: Vec<(String, String)> = Vec::new();
+    let mut current: String = String::new();
+    let mut quoted: bool = false;
+    let mut escaped: bool = false;
+    let mut segments: Vec<String> = Vec::new();
+
+    for ch in value.chars() {
+        if escaped {
+            current.push(ch);
+            escaped = false;
+        } else if quoted && ch == '\\' {
+            escaped = true;
+        } else if ch == '"' {
+            quoted = !quoted;
+        } else if ch == ';' && !quoted {
+            segments.push(std::mem::take(&mut current));
+        } else {
+            current.push(ch);
+        }
+    }
+    segments.push(current);
+
+    segments.iter().skip(1).for_each(|x: &String| {
+        if let Some((k, v)) = x.split_once('=') {
+            params.push((k.trim().to_lowercase(), v.trim().to_owned()));
+        }
+    });
+
+    params
+}
+/*
+ * Position of a Byte Sequence
+ */
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|w: &[u8]| w == needle)
+}
@@ -1,25 +1,25 @@
-pub(crate) async fn parse_path(header: String) -> (String, String, String) {
+/*
+ * Request Target, Path & Query String from the Request Line
+ * Path & Query end at a Fragment, the Query at the first `?` keeps later ones
+ */
+pub(crate) async fn parse_path(header: &str) -> (String, String, String) {
     let url: String = header
         .lines()
         .next()
         .expect("[Error] Fail to read header lines for Path")
-        .to_owned()
         .split_whitespace()
-        .map(|x: &str| x.to_owned())
-        .into_iter()
         .nth(1)
-        .unwrap_or_else(|| "/".to_owned());
+        .unwrap_or("/")
+        .to_owned();
 
-    let url_split: Vec<String> = url.split('?').map(|x: &str| x.to_owned()).collect();
-
-    let path: String = match url_split.get(0) {
-        Some(x) => x.to_owned(),
-        None => String::new(),
+    let target: &str = match url.split_once('#') {
+        Some((x, _)) => x,
+        None => &url,
     };
 
-    let query: String = match url_split.get(1) {
-        Some(x) => x.to_owned(),
-        None => String::new(),
+    let (path, query): (String, String) = match target.split_once('?') {
+        Some((p, q)) => (p.to_owned(), q.to_owned()),
+        None => (target.to_owned(), String::new()),
     };
 
     (url, path, query)
@@ -0,0 +1,25 @@
+use crate::utils::percent_decode::percent_decode;
+
+/*
+ * Parse Query String (`q=rust&page=2`) into Key Value pairs
+ */
+pub(crate) async fn parse_query(query: String) -> Vec<(String, String)> {
+    let mut query_str: Vec<(String, String)> = Vec::new();
+
+    for q in query.split('&') {
+        let mut kv: Vec<&str> = q.split('=').collect();
+
+        if kv.is_empty() || kv[0].is_empty() {
+            continue;
+        }
+
+        let k: String = percent_decode(kv[0], true);
+        kv.remove(0);
+
+        let v: String = percent_decode(&kv.join("="), true);
+
+        query_str.push((k, v));
+    }
+
+    query_str
+}
@@ -0,0 +1,70 @@
+/*
+ * Parse Range Header (`bytes=0-99, 200-, -50`) for a Length
+ * Inclusive Ranges clamped to the Length, sorted & merged
+ * None to ignore the Header (other Unit, malformed, more than 16 Ranges)
+ * Empty when no Range is satisfiable (416)
+ */
+pub(crate) fn parse_range(range: &str, len: u64) -> Option<Vec<(u64, u64)>> {
+    let specs: &str = range.trim().strip_prefix("bytes=")?;
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+
+    for (i, spec) in specs.split(',').enumerate() {
+        if i >= 16 {
+            return None;
+        }
+
+        let (start, end) = spec.trim().split_once('-')?;
+        let (start, end) = (start.trim(), end.trim());
+
+        let parsed: (u64, u64) = match (start.is_empty(), end.is_empty()) {
+            /*
+             * Suffix Range: last N Bytes
+             */
+            (true, false) => {
+                let n: u64 = end.parse().ok()?;
+
+                if n == 0 || len == 0 {
+                    continue;
+                }
+
+                (len.saturating_sub(n), len - 1)
+            }
+            (false, _) => {
+                let start: u64 = start.parse().ok()?;
+                let end: u64 = match end.is_empty() {
+                    true => u64::MAX,
+                    false => end.parse().ok()?,
+                };
+
+                if end < start {
+                    return None;
+                }
+
+                if start >= len {
+                    continue;
+                }
+
+                (start, end.min(len - 1))
+            }
+            (true, true) => return None,
+        };
+
+        ranges.push(parsed);
+    }
+
+    /*
+     * Merge overlapping & adjacent Ranges, never more Bytes than the Length
+     */
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    Some(merged)
+}
@@ -0,0 +1,40 @@
+/*
+ * Percent Decode (`%20` => ` `)
+ * Optionally decode `+` as Space (Query String & Form Body)
+ * Invalid Sequences are kept as they are
+ */
+pub(crate) fn percent_decode(value: &str, plus_as_space: bool) -> String {
+    let bytes: &[u8] = value.as_bytes();
+    let mut decoded: Vec<u8> = Vec::with_capacity(bytes.len());
+
+    let mut i: usize = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex: Option<u8> = match (
+                    (bytes[i + 1] as char).to_digit(16),
+                    (bytes[i + 2] as char).to_digit(16),
+                ) {
+                    (Some(h), Some(l)) => Some((h * 16 + l) as u8),
+                    _ => None,
+                };
+
+                match hex {
+                    Some(b) => {
+                        decoded.push(b);
+                        i += 3;
+                        continue;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            b'+' if plus_as_space => decoded.push(b' '),
+            b => decoded.push(b),
+        }
+
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
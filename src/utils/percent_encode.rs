@@ -0,0 +1,15 @@
+/*
+ * Percent Encode (` ` => `%20`)
+ * Keeps Unreserved Characters (RFC 3986) as they are
+ */
+pub(crate) async fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b: u8| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use crate::utils::read_timeout::read_timeout;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, Error, ErrorKind};
+use tokio::sync::mpsc::Sender;
+
+/*
+ * Send Request Body Chunks up to Content Length to the Body Reader
+ * Bytes past the Body stay in the Reader for the next Request
+ * Returns whether the whole Body was read (the Connection can be kept)
+ */
+pub(crate) async fn pump_body<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    length: usize,
+    timeout: Option<Duration>,
+    sender: Sender<Result<Vec<u8>, Error>>,
+    on_error: &ErrorHandler,
+) -> bool {
+    let mut remaining: usize = length;
+
+    while remaining > 0 {
+        let data: Vec<u8> = match read_timeout(timeout, reader.fill_buf()).await {
+            Some(Ok([])) => {
+                sender.send(Err(ErrorKind::UnexpectedEof.into())).await.ok();
+                return false;
+            }
+            Some(Ok(x)) => x[..remaining.min(x.len())].to_vec(),
+            Some(Err(e)) => {
+                sender
+                    .send(Err(Error::new(e.kind(), e.to_string())))
+                    .await
+                    .ok();
+                on_error(&ServerError::Read(e));
+                return false;
+            }
+            None => {
+                sender.send(Err(ErrorKind::TimedOut.into())).await.ok();
+                return false;
+            }
+        };
+
+        reader.consume(data.len());
+        remaining -= data.len();
+        /*
+         * Reader dropped, the rest is still read to keep the Connection
+         */
+        sender.send(Ok(data)).await.ok();
+    }
+
+    true
+}
@@ -0,0 +1,10 @@
+/*
+ * Random Hex Token from the OS Random Source (Session IDs, CSRF Tokens)
+ */
+pub(crate) fn random_token(bytes: usize) -> String {
+    let mut buffer: Vec<u8> = vec![0; bytes];
+
+    getrandom::getrandom(&mut buffer).expect("[Error] OS Random Source unavailable");
+
+    buffer.iter().map(|x: &u8| format!("{:02x}", x)).collect()
+}
@@ -0,0 +1,175 @@
+use crate::structs::multipart::{Multipart, Part};
+use crate::structs::multipart_error::MultipartError;
+use crate::structs::temp_file::TempFile;
+use crate::structs::uploads::Uploads;
+use crate::utils::parse_multipart::{find, parse_part_head};
+use std::sync::Arc;
+use tokio::fs::File;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+
+/*
+ * Part Headers larger than this are malformed
+ */
+const MAX_HEAD: usize = 16 * 1024;
+/*
+ * Read Multipart Body split on `--{boundary}` as it arrives
+ * File Parts are written to Temporary Files, Fields kept in Memory
+ */
+pub(crate) async fn read_multipart<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    boundary: &str,
+    uploads: &Uploads,
+) -> Result<Multipart, MultipartError> {
+    let delimiter: Vec<u8> = format!("--{}", boundary).into_bytes();
+    let next_delimiter: Vec<u8> = format!("\r\n--{}", boundary).into_bytes();
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let mut chunk: Vec<u8> = vec![0; 64 * 1024];
+    /*
+     * Preamble before the first Delimiter is ignored
+     */
+    loop {
+        if let Some(i) = find(&buffer, &delimiter) {
+            buffer.drain(..i + delimiter.len());
+            break;
+        }
+
+        let keep: usize = delimiter.len() - 1;
+
+        if buffer.len() > keep {
+            buffer.drain(..buffer.len() - keep);
+        }
+
+        if !fill(reader, &mut chunk, &mut buffer).await? {
+            return Err(MultipartError::Malformed("boundary not found".to_owned()));
+        }
+    }
+
+    let mut parts: Vec<Part> = Vec::new();
+
+    loop {
+        while buffer.len() < 2 {
+            if !fill(reader, &mut chunk, &mut buffer).await? {
+                return Err(MultipartError::Malformed("invalid delimiter".to_owned()));
+            }
+        }
+        /*
+         * Close Delimiter
+         */
+        if buffer.starts_with(b"--") {
+            return Ok(Multipart { parts });
+        }
+
+        if !buffer.starts_with(b"\r\n") {
+            return Err(MultipartError::Malformed("invalid delimiter".to_owned()));
+        }
+
+        buffer.drain(..2);
+        /*
+         * Part Headers
+         */
+        let (head, skip): (Vec<u8>, usize) = loop {
+            if buffer.starts_with(b"\r\n") {
+                break (Vec::new(), 2);
+            }
+
+            if let Some(i) = find(&buffer, b"\r\n\r\n") {
+                break (buffer[..i].to_vec(), i + 4);
+            }
+
+            if buffer.len() > MAX_HEAD {
+                return Err(MultipartError::Malformed(
+                    "part headers too large".to_owned(),
+                ));
+            }
+
+            if !fill(reader, &mut chunk, &mut buffer).await? {
+                return Err(MultipartError::Malformed("part without headers".to_owned()));
+            }
+        };
+
+        buffer.drain(..skip);
+
+        let mut part: Part = parse_part_head(&head).await?;
+        /*
+         * Part Content up to the next Delimiter
+         * A Tail which may start the Delimiter stays in the Buffer
+         */
+        let mut file: Option<(TempFile, File)> = match part.filename {
+            Some(_) => Some(
+                TempFile::create(&uploads.temp_dir)
+                    .await
+                    .map_err(|e| MultipartError::Io(e.to_string()))?,
+            ),
+            None => None,
+        };
+
+        let limit: usize = match file {
+            Some(_) => uploads.max_file_size,
+            None => uploads.max_field_size,
+        };
+
+        let mut size: usize = 0;
+
+        loop {
+            let (n, done): (usize, bool) = match find(&buffer, &next_delimiter) {
+                Some(i) => (i, true),
+                None => (buffer.len().saturating_sub(next_delimiter.len() - 1), false),
+            };
+
+            size += n;
+
+            if size > limit {
+                return Err(MultipartError::TooLarge(part.name));
+            }
+
+            match file.as_mut() {
+                Some((_, x)) => x
+                    .write_all(&buffer[..n])
+                    .await
+                    .map_err(|e| MultipartError::Io(e.to_string()))?,
+                None => part.data.extend_from_slice(&buffer[..n]),
+            }
+
+            buffer.drain(..n);
+
+            if done {
+                buffer.drain(..next_delimiter.len());
+                break;
+            }
+
+            if !fill(reader, &mut chunk, &mut buffer).await? {
+                return Err(MultipartError::Malformed("unterminated part".to_owned()));
+            }
+        }
+
+        if let Some((mut temp, mut x)) = file {
+            x.flush()
+                .await
+                .map_err(|e| MultipartError::Io(e.to_string()))?;
+
+            temp.size = size as u64;
+            part.file = Some(Arc::new(temp));
+        }
+
+        parts.push(part);
+    }
+}
+/*
+ * Read more Bytes into the Buffer through the Chunk of the Upload
+ * Returns false at the End of the Body
+ */
+async fn fill<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    chunk: &mut [u8],
+    buffer: &mut Vec<u8>,
+) -> Result<bool, MultipartError> {
+    match reader.read(chunk).await {
+        Ok(0) => Ok(false),
+        Ok(n) => {
+            buffer.extend_from_slice(&chunk[..n]);
+            Ok(true)
+        }
+        Err(e) => Err(MultipartError::Io(e.to_string())),
+    }
+}
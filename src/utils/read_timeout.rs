@@ -0,0 +1,17 @@
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::timeout;
+
+/*
+ * Await Future within optional Read Timeout
+ * Returns None when the Timeout elapses
+ */
+pub(crate) async fn read_timeout<F: Future>(
+    duration: Option<Duration>,
+    future: F,
+) -> Option<F::Output> {
+    match duration {
+        Some(d) => timeout(d, future).await.ok(),
+        None => Some(future.await),
+    }
+}
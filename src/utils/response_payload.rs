@@ -1,58 +1,449 @@
+use crate::server::{ErrorHandler, Server};
+use crate::structs::chunk_writer::ChunkWriter;
 use crate::structs::context::Context;
+use crate::structs::payload::{Payload, StreamFunc};
+use crate::structs::server_error::ServerError;
+use crate::utils::http_date::http_date;
+use crate::utils::not_modified::not_modified;
+use crate::utils::panic_message::panic_message;
+use crate::utils::parse_range::parse_range;
+use crate::utils::random_token::random_token;
 use crate::utils::status_string::status_string;
-use tokio::io::{AsyncWriteExt, Error};
-use tokio::net::tcp::OwnedWriteHalf;
+use futures::FutureExt;
+use std::io::SeekFrom;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs::File;
+use tokio::io::{copy, AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, Error, ErrorKind};
+use tokio::join;
+use tokio::sync::mpsc::{channel, Receiver};
 
-pub(crate) async fn response_payload(
-    mut writer: OwnedWriteHalf,
-    context: Context,
+pub(crate) async fn response_payload<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    mut context: Context,
     http_version: f64,
-) {
+    server: &Server,
+) -> bool {
+    let on_error: &ErrorHandler = &server.on_error;
+    /*
+     * Open Payload File / Take Payload Bytes
+     */
+    let mut file: Option<(File, u64)> = None;
+    let mut bytes: Option<Vec<u8>> = None;
+    let mut stream: Option<StreamFunc> = None;
+
+    match context.response.payload.take() {
+        Some(Payload::Bytes(x)) => bytes = Some(x),
+        Some(Payload::Stream(x)) => stream = x.take(),
+        x => context.response.payload = x,
+    }
+
+    if let Some(Payload::File(path)) = context.response.payload.clone() {
+        let file_open: Result<(File, u64, Option<SystemTime>), Error> = async {
+            let f: File = File::open(&path).await?;
+            let metadata = f.metadata().await?;
+            Ok((f, metadata.len(), metadata.modified().ok()))
+        }
+        .await;
+
+        match file_open {
+            /*
+             * Validators from Size & Modification Time
+             * Current cached Copies respond 304 without the File
+             */
+            Ok((f, len, Some(modified))) => {
+                let mtime: u64 = modified
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0);
+
+                let etag: String = match context.response.get_header("etag").await {
+                    Some(x) => x,
+                    None => {
+                        let x: String = format!("\"{:x}-{:x}\"", mtime, len);
+                        context.response.set_header("ETag", &x).await;
+                        x
+                    }
+                };
+
+                if context.response.get_header("last-modified").await.is_none() {
+                    context
+                        .response
+                        .set_header("Last-Modified", &http_date(modified))
+                        .await;
+                }
+
+                let method: String = context.request.method.to_uppercase();
+
+                let fresh: bool = context.response.status == 200
+                    && (method == "GET" || method == "HEAD")
+                    && not_modified(
+                        context.request.header("if-none-match").await,
+                        context.request.header("if-modified-since").await,
+                        &etag,
+                        modified,
+                    );
+
+                if fresh {
+                    context.response.status = 304;
+                    context.response.body = String::new();
+                } else {
+                    file = Some((f, len));
+                }
+            }
+            Ok((f, len, None)) => file = Some((f, len)),
+            Err(e) => {
+                on_error(&ServerError::File(path, e));
+
+                context.response.status = 500;
+                context.response.body = "Internal Server Error".to_owned();
+                context.response.content_type = "text/plain".to_owned();
+            }
+        }
+    }
+
+    /*
+     * Byte Ranges of the File (GET only)
+     * Parts are (Start, End, Part Header), one Range without Part Header,
+     * several as `multipart/byteranges` closed by the Boundary
+     */
+    let mut parts: Vec<(u64, u64, String)> = Vec::new();
+    let mut boundary_end: String = String::new();
+
+    if let Some((_, len)) = &file {
+        let len: u64 = *len;
+
+        context.response.set_header("Accept-Ranges", "bytes").await;
+
+        let range: Option<String> =
+            if context.response.status == 200 && context.request.method.to_uppercase() == "GET" {
+                context.request.header("range").await
+            } else {
+                None
+            };
+        /*
+         * `If-Range` other than the current Validator serves the whole File
+         */
+        let if_range_valid: bool = match context.request.header("if-range").await {
+            Some(x) => {
+                Some(&x) == context.response.get_header("etag").await.as_ref()
+                    || Some(&x) == context.response.get_header("last-modified").await.as_ref()
+            }
+            None => true,
+        };
+
+        match range
+            .filter(|_| if_range_valid)
+            .and_then(|x: String| parse_range(&x, len))
+        {
+            Some(x) if x.is_empty() => {
+                file = None;
+                context.response.status = 416;
+                context.response.body = String::new();
+                context
+                    .response
+                    .set_header("Content-Range", &format!("bytes */{}", len))
+                    .await;
+            }
+            Some(x) if x.len() == 1 => {
+                let (start, end): (u64, u64) = x[0];
+
+                context.response.status = 206;
+                context
+                    .response
+                    .set_header("Content-Range", &format!("bytes {}-{}/{}", start, end, len))
+                    .await;
+
+                parts.push((start, end, String::new()));
+            }
+            Some(x) => {
+                let boundary: String = random_token(16);
+                let part_type: String = context
+                    .response
+                    .get_header("content-type")
+                    .await
+                    .unwrap_or(context.response.content_type.clone());
+                let part_type: String = if part_type.is_empty() {
+                    server.default_content_type.clone()
+                } else {
+                    part_type
+                };
+
+                context.response.status = 206;
+                context.response.del_header("content-type").await;
+                context.response.content_type =
+                    format!("multipart/byteranges; boundary={}", boundary);
+
+                x.iter().for_each(|(start, end)| {
+                    parts.push((
+                        *start,
+                        *end,
+                        format!(
+                            "\r\n--{}\r\nContent-Type: {}\r\nContent-Range: bytes {}-{}/{}\r\n\r\n",
+                            boundary, part_type, start, end, len
+                        ),
+                    ));
+                });
+
+                boundary_end = format!("\r\n--{}--\r\n", boundary);
+            }
+            None => {}
+        }
+    }
+
+    let content_length: u64 = match (&file, &bytes) {
+        (Some(_), _) if !parts.is_empty() => {
+            parts
+                .iter()
+                .map(|(start, end, header)| end - start + 1 + header.len() as u64)
+                .sum::<u64>()
+                + boundary_end.len() as u64
+        }
+        (Some((_, len)), _) => *len,
+        (None, Some(x)) => x.len() as u64,
+        (None, None) => context.response.body.len() as u64,
+    };
+    /*
+     * Streamed Body has no Content Length
+     * Chunked for HTTP/1.1, raw until the Connection closes for HTTP/1.0
+     * 101 & 304 have neither Body nor Content Length
+     */
+    let chunked: bool = stream.is_some() && http_version >= 1.1;
+    let length_header: String = if matches!(context.response.status, 101 | 304) {
+        String::new()
+    } else if stream.is_none() {
+        format!("Content-Length: {}\r\n", content_length)
+    } else if chunked {
+        "Transfer-Encoding: chunked\r\n".to_owned()
+    } else {
+        String::new()
+    };
+    /*
+     * HEAD keeps Headers & Content Length without Body
+     */
+    if context.request.method.to_uppercase() == "HEAD" {
+        file = None;
+        bytes = None;
+        stream = None;
+        context.response.body = String::new();
+    }
     /*
      * Prepare Response Headers
      */
     let mut response_header: String = String::new();
+    let mut content_type: String = context.response.content_type.clone();
 
     context.response.header.iter().for_each(|(k, v)| {
+        /*
+         * Content Type Header overrides Content Type Field
+         * Content Length is always computed
+         */
+        if k.to_lowercase() == "content-type" {
+            content_type = v.to_owned();
+            return;
+        }
+
+        if k.to_lowercase() == "content-length" || k.to_lowercase() == "transfer-encoding" {
+            return;
+        }
+
         response_header.push_str(&format!("{}: {}\r\n", k, v));
     });
+    /*
+     * Default Content Type when the Functions set none
+     */
+    if content_type.is_empty() {
+        content_type = server.default_content_type.clone();
+    }
     /*
      * Prepare Response Payload
      */
     let status_str: String = status_string(context.response.status).await;
+    /*
+     * Switching Protocols has no Content Type
+     */
+    let content_type_header: String = if context.response.status == 101 {
+        String::new()
+    } else {
+        format!("Content-Type: {}\r\n", content_type)
+    };
+
+    /*
+     * Body written with the Headers unless it is a File, Bytes or Stream
+     */
+    let inline_body: usize = if file.is_some() || bytes.is_some() || stream.is_some() {
+        0
+    } else {
+        context.response.body.len()
+    };
 
     let response: String = format!(
-        "HTTP/{0} {1} {2}\r\n{3}Content-Type: {4}\r\nContent-Length: {5}\r\n\r\n{6}",
+        "HTTP/{0:.1} {1} {2}\r\n{3}{4}{5}\r\n{6}",
         http_version,
         context.response.status,
         status_str,
         response_header,
-        context.response.content_type,
-        context.response.body.len(),
-        context.response.body,
+        content_type_header,
+        length_header,
+        if inline_body > 0 {
+            &context.response.body
+        } else {
+            ""
+        },
     );
     /*
-     * Write Payload
+     * Body Bytes are counted on the Context, shared with its Clones
      */
-    let stream_write: Result<(), Error> = writer.write_all(response.as_bytes()).await;
+    let size: &AtomicUsize = &context.response_size;
+
+    let written: bool = async {
+        /*
+         * Write Payload
+         */
+        let stream_write: Result<(), Error> = writer.write_all(response.as_bytes()).await;
+
+        if let Err(e) = stream_write {
+            on_error(&ServerError::write(e));
+
+            return false;
+        }
+
+        size.fetch_add(inline_body, Ordering::Relaxed);
+        /*
+         * Write Bytes
+         */
+        if let Some(x) = bytes {
+            let stream_write: Result<(), Error> = writer.write_all(&x).await;
+
+            if let Err(e) = stream_write {
+                on_error(&ServerError::write(e));
+
+                return false;
+            }
+
+            size.fetch_add(x.len(), Ordering::Relaxed);
+        }
+        /*
+         * Stream File or its Ranges
+         */
+        if let Some((mut f, _)) = file {
+            let stream_copy: Result<(), Error> = if parts.is_empty() {
+                copy(&mut f, writer).await.map(|x: u64| {
+                    size.fetch_add(x as usize, Ordering::Relaxed);
+                })
+            } else {
+                write_parts(&mut f, writer, &parts, &boundary_end, size).await
+            };
+
+            if let Err(e) = stream_copy {
+                on_error(&ServerError::write(e));
+
+                return false;
+            }
+        }
+        /*
+         * Stream Chunks while the Function produces them
+         */
+        if let Some(func) = stream {
+            let (sender, receiver) = channel::<Vec<u8>>(16);
+
+            let produce = async move {
+                match AssertUnwindSafe(func(ChunkWriter { sender }))
+                    .catch_unwind()
+                    .await
+                {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) if e.kind() == ErrorKind::BrokenPipe => {}
+                    Ok(Err(e)) => on_error(&ServerError::Stream(e)),
+                    Err(e) => on_error(&ServerError::Panic(panic_message(&*e))),
+                }
+            };
 
-    if stream_write.is_err() {
-        println!(
-            "[Error] Fail to Write Stream:\n{}",
-            stream_write.err().unwrap()
-        );
+            let (_, stream_write) =
+                join!(produce, write_chunks(&mut *writer, receiver, chunked, size));
 
-        return;
+            if let Err(e) = stream_write {
+                on_error(&ServerError::write(e));
+
+                return false;
+            }
+        }
+        /*
+         * Flush Payload
+         */
+        let stream_flush: Result<(), Error> = writer.flush().await;
+
+        if let Err(e) = stream_flush {
+            on_error(&ServerError::write(e));
+
+            return false;
+        }
+
+        true
     }
+    .await;
     /*
-     * Flush Payload
+     * Access Log Line after the Response was written
      */
-    let stream_flush: Result<(), Error> = writer.flush().await;
+    if let Some(x) = &server.access_log {
+        x.write(&mut context).await;
+    }
 
-    if stream_flush.is_err() {
-        println!(
-            "[Error] Fail to Flush Stream:\n{}",
-            stream_flush.err().unwrap()
-        );
+    written
+}
+/*
+ * Write each Range of the File after its Part Header, then the Boundary
+ */
+async fn write_parts<W: AsyncWrite + Unpin>(
+    f: &mut File,
+    writer: &mut W,
+    parts: &[(u64, u64, String)],
+    boundary_end: &str,
+    size: &AtomicUsize,
+) -> Result<(), Error> {
+    for (start, end, header) in parts {
+        writer.write_all(header.as_bytes()).await?;
+
+        f.seek(SeekFrom::Start(*start)).await?;
+        let n: u64 = copy(&mut (&mut *f).take(end - start + 1), writer).await?;
+
+        size.fetch_add(header.len() + n as usize, Ordering::Relaxed);
     }
+
+    writer.write_all(boundary_end.as_bytes()).await?;
+    size.fetch_add(boundary_end.len(), Ordering::Relaxed);
+
+    Ok(())
+}
+/*
+ * Write & Flush each Chunk, then the terminating zero-length Chunk
+ * The Receiver is dropped on Error, so the Producer stops
+ */
+async fn write_chunks<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    mut receiver: Receiver<Vec<u8>>,
+    chunked: bool,
+    size: &AtomicUsize,
+) -> Result<(), Error> {
+    while let Some(x) = receiver.recv().await {
+        if chunked {
+            let mut chunk: Vec<u8> = format!("{:X}\r\n", x.len()).into_bytes();
+            chunk.extend_from_slice(&x);
+            chunk.extend_from_slice(b"\r\n");
+
+            writer.write_all(&chunk).await?;
+        } else {
+            writer.write_all(&x).await?;
+        }
+
+        writer.flush().await?;
+        size.fetch_add(x.len(), Ordering::Relaxed);
+    }
+
+    if chunked {
+        writer.write_all(b"0\r\n\r\n").await?;
+    }
+
+    Ok(())
 }
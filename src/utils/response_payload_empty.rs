@@ -1,17 +1,18 @@
-use tokio::io::{AsyncWriteExt, Error};
-use tokio::net::tcp::OwnedWriteHalf;
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use tokio::io::{AsyncWrite, AsyncWriteExt, Error};
 
-pub(crate) async fn response_payload_empty(mut writer: OwnedWriteHalf) {
+pub(crate) async fn response_payload_empty<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    on_error: &ErrorHandler,
+) {
     /*
      * Write Payload
      */
     let stream_write: Result<(), Error> = writer.write_all("".as_bytes()).await;
 
-    if stream_write.is_err() {
-        println!(
-            "[Error] Fail to Write Empty Stream:\n{}",
-            stream_write.err().unwrap()
-        );
+    if let Err(e) = stream_write {
+        on_error(&ServerError::write(e));
 
         return;
     }
@@ -20,10 +21,7 @@ pub(crate) async fn response_payload_empty(mut writer: OwnedWriteHalf) {
      */
     let stream_flush: Result<(), Error> = writer.flush().await;
 
-    if stream_flush.is_err() {
-        println!(
-            "[Error] Fail to Flush Empty Stream:\n{}",
-            stream_flush.err().unwrap()
-        );
+    if let Err(e) = stream_flush {
+        on_error(&ServerError::write(e));
     }
 }
@@ -0,0 +1,41 @@
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use crate::utils::status_string::status_string;
+use tokio::io::{AsyncWrite, AsyncWriteExt, Error};
+
+/*
+ * Write a bare Status Response & close the Connection
+ * Used before a Request could be parsed into a Context
+ */
+pub(crate) async fn response_payload_status<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    status: usize,
+    on_error: &ErrorHandler,
+) {
+    let status_str: String = status_string(status).await;
+
+    let response: String = format!(
+        "HTTP/1.1 {0} {1}\r\nConnection: close\r\nContent-Type: text/plain\r\nContent-Length: {2}\r\n\r\n{1}",
+        status,
+        status_str,
+        status_str.len(),
+    );
+    /*
+     * Write Payload
+     */
+    let stream_write: Result<(), Error> = writer.write_all(response.as_bytes()).await;
+
+    if let Err(e) = stream_write {
+        on_error(&ServerError::write(e));
+
+        return;
+    }
+    /*
+     * Flush Payload
+     */
+    let stream_flush: Result<(), Error> = writer.flush().await;
+
+    if let Err(e) = stream_flush {
+        on_error(&ServerError::write(e));
+    }
+}
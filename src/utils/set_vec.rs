@@ -5,8 +5,8 @@ pub(crate) async fn set_vec(
 ) -> Vec<(String, String)> {
     let mut obj: Vec<(String, String)> = obj
         .iter()
-        .cloned()
         .filter(|(k, _)| k.to_owned().to_lowercase() != key.to_lowercase())
+        .cloned()
         .collect();
 
     obj.push((key, value));
@@ -0,0 +1,60 @@
+use crate::structs::context::Context;
+use crate::structs::definition::Returns;
+use crate::structs::payload::Payload;
+use crate::utils::mime_type::mime_type;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::fs::{canonicalize, metadata};
+
+/*
+ * Serve File under Root Directory from Wildcard Parameter
+ * Cached by Clients for `max_age` if set
+ */
+pub(crate) async fn static_file(
+    mut c: Context,
+    root: PathBuf,
+    max_age: Option<Duration>,
+) -> Returns {
+    let path: String = c.request.param("path").await;
+    /*
+     * Path Traversal
+     */
+    if path.split('/').any(|x: &str| x == ".." || x.contains('\\')) {
+        c.response.status = 403;
+        c.response.body = "Forbidden".to_owned();
+        return (c, None);
+    }
+
+    let file: PathBuf = root.join(path.trim_start_matches('/'));
+    /*
+     * Missing File
+     */
+    let is_file: bool = metadata(&file).await.map(|x| x.is_file()).unwrap_or(false);
+
+    if !is_file {
+        c.response.status = 404;
+        c.response.body = "Not Found".to_owned();
+        return (c, None);
+    }
+    /*
+     * Symlink Escaping Root
+     */
+    let (file_real, root_real) = (canonicalize(&file).await, canonicalize(&root).await);
+
+    if !matches!((&file_real, &root_real), (Ok(f), Ok(r)) if f.starts_with(r)) {
+        c.response.status = 403;
+        c.response.body = "Forbidden".to_owned();
+        return (c, None);
+    }
+
+    c.response.content_type = mime_type(&file).await.to_owned();
+    c.response.payload = Some(Payload::File(file));
+
+    if let Some(x) = max_age {
+        c.response
+            .set_header("Cache-Control", &format!("public, max-age={}", x.as_secs()))
+            .await;
+    }
+
+    (c, None)
+}
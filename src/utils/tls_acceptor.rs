@@ -0,0 +1,35 @@
+use std::io::{Error, ErrorKind};
+use std::sync::Arc;
+use tokio_rustls::rustls::crypto::ring::default_provider;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::TlsAcceptor;
+
+/*
+ * Build TLS Acceptor from PEM Certificate Chain & Private Key Files
+ */
+pub(crate) fn tls_acceptor(cert_path: &str, key_path: &str) -> Result<TlsAcceptor, Error> {
+    let certs: Vec<CertificateDer<'static>> = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|x| x.collect())
+        .map_err(|e| invalid_input(cert_path, e))?;
+
+    let key: PrivateKeyDer<'static> =
+        PrivateKeyDer::from_pem_file(key_path).map_err(|e| invalid_input(key_path, e))?;
+
+    let mut config: ServerConfig =
+        ServerConfig::builder_with_provider(Arc::new(default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(|e| invalid_input(cert_path, e))?
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .map_err(|e| invalid_input(cert_path, e))?;
+
+    config.alpn_protocols = vec![b"http/1.1".to_vec(), b"http/1.0".to_vec()];
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+fn invalid_input(path: &str, e: impl std::fmt::Display) -> Error {
+    Error::new(ErrorKind::InvalidInput, format!("{}: {}", path, e))
+}
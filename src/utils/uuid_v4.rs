@@ -0,0 +1,23 @@
+use crate::utils::random_token::random_token;
+
+/*
+ * Random UUID (Version 4) from the OS Random Source, e.g.
+ * `3b241101-e2bb-4255-8caf-4136c566a962`
+ */
+pub(crate) fn uuid_v4() -> String {
+    let mut x: Vec<char> = random_token(16).chars().collect();
+    /*
+     * Version 4 & RFC 4122 Variant
+     */
+    x[12] = '4';
+    x[16] = ['8', '9', 'a', 'b'][x[16].to_digit(16).unwrap_or(0) as usize % 4];
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        x[0..8].iter().collect::<String>(),
+        x[8..12].iter().collect::<String>(),
+        x[12..16].iter().collect::<String>(),
+        x[16..20].iter().collect::<String>(),
+        x[20..32].iter().collect::<String>(),
+    )
+}
@@ -0,0 +1,68 @@
+/*
+ * Validate Request Header before Dispatch
+ * Request Line `METHOD /target HTTP/x.y` & `Name: Value` Header Lines
+ */
+pub(crate) fn valid_request(header: &str) -> bool {
+    let mut lines = header.lines();
+    /*
+     * Request Line
+     */
+    let request_line: Vec<&str> = match lines.next() {
+        Some(x) => x.split_whitespace().collect(),
+        None => return false,
+    };
+
+    let (method, target, version) = match request_line[..] {
+        [method, target, version] => (method, target, version),
+        _ => return false,
+    };
+    /*
+     * Method is a Token (RFC 7230)
+     */
+    if !method
+        .chars()
+        .all(|x: char| x.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(x))
+    {
+        return false;
+    }
+    /*
+     * Origin Form (`/path?query`) or Asterisk Form (`OPTIONS *`)
+     */
+    if !(target.starts_with('/') || target == "*") || target.chars().any(|x: char| x.is_control()) {
+        return false;
+    }
+
+    let version_valid: bool = match version.strip_prefix("HTTP/") {
+        Some(x) => {
+            let x: Vec<char> = x.chars().collect();
+            x.len() == 3 && x[0].is_ascii_digit() && x[1] == '.' && x[2].is_ascii_digit()
+        }
+        None => false,
+    };
+
+    if !version_valid {
+        return false;
+    }
+    /*
+     * Header Lines
+     * Name without Whitespace (no obsolete Line Folding), numeric Content Length
+     */
+    lines.all(|ln: &str| {
+        let (k, v) = match ln.split_once(':') {
+            Some(x) => x,
+            None => return false,
+        };
+
+        if k.is_empty() || k.contains(char::is_whitespace) {
+            return false;
+        }
+
+        if k.eq_ignore_ascii_case("content-length") {
+            let v: &str = v.trim();
+
+            return v.chars().all(|x: char| x.is_ascii_digit()) && v.parse::<usize>().is_ok();
+        }
+
+        true
+    })
+}
@@ -0,0 +1,95 @@
+/*
+ * WebSocket Frame (RFC 6455)
+ */
+pub(crate) struct Frame {
+    pub(crate) fin: bool,
+    pub(crate) opcode: u8,
+    pub(crate) payload: Vec<u8>,
+}
+/*
+ * Parse a masked Client Frame from the Start of the Buffer
+ * Ok(None) until the Frame is complete, Err(Close Code) on Protocol Errors
+ */
+pub(crate) fn parse_frame(buffer: &mut Vec<u8>, max_size: usize) -> Result<Option<Frame>, u16> {
+    if buffer.len() < 2 {
+        return Ok(None);
+    }
+
+    let fin: bool = buffer[0] & 0x80 != 0;
+    let opcode: u8 = buffer[0] & 0x0f;
+    /*
+     * No Extensions (RSV Bits) & Client Frames are masked
+     */
+    if buffer[0] & 0x70 != 0 || buffer[1] & 0x80 == 0 {
+        return Err(1002);
+    }
+
+    let (len, offset): (u64, usize) = match buffer[1] & 0x7f {
+        126 if buffer.len() >= 4 => (u16::from_be_bytes([buffer[2], buffer[3]]) as u64, 4),
+        127 if buffer.len() >= 10 => {
+            let mut x: [u8; 8] = [0; 8];
+            x.copy_from_slice(&buffer[2..10]);
+            (u64::from_be_bytes(x), 10)
+        }
+        126 | 127 => return Ok(None),
+        x => (x as u64, 2),
+    };
+    /*
+     * Control Frames are final & small
+     */
+    if opcode >= 0x8 && (!fin || len > 125) {
+        return Err(1002);
+    }
+
+    if len > max_size as u64 {
+        return Err(1009);
+    }
+
+    let len: usize = len as usize;
+
+    if buffer.len() < offset + 4 + len {
+        return Ok(None);
+    }
+
+    let mask: [u8; 4] = [
+        buffer[offset],
+        buffer[offset + 1],
+        buffer[offset + 2],
+        buffer[offset + 3],
+    ];
+
+    let payload: Vec<u8> = buffer[offset + 4..offset + 4 + len]
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x ^ mask[i % 4])
+        .collect();
+
+    buffer.drain(..offset + 4 + len);
+
+    Ok(Some(Frame {
+        fin,
+        opcode,
+        payload,
+    }))
+}
+/*
+ * Encode a final unmasked Server Frame
+ */
+pub(crate) fn encode_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame: Vec<u8> = vec![0x80 | opcode];
+
+    match payload.len() {
+        x if x < 126 => frame.push(x as u8),
+        x if x <= u16::MAX as usize => {
+            frame.push(126);
+            frame.extend_from_slice(&(x as u16).to_be_bytes());
+        }
+        x => {
+            frame.push(127);
+            frame.extend_from_slice(&(x as u64).to_be_bytes());
+        }
+    }
+
+    frame.extend_from_slice(payload);
+    frame
+}
@@ -0,0 +1,267 @@
+use crate::server::ErrorHandler;
+use crate::structs::server_error::ServerError;
+use crate::structs::websocket::{Message, WebSocket, WebSocketFunc};
+use crate::utils::panic_message::panic_message;
+use crate::utils::read_timeout::read_timeout;
+use crate::utils::websocket_frame::{encode_frame, parse_frame, Frame};
+use futures::FutureExt;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, Error};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::sync::watch::Receiver as WatchReceiver;
+use tokio::{join, pin, select};
+
+/*
+ * Run the WebSocket Function on the upgraded Connection
+ * Frames are read & written here, Messages pass through Channels
+ */
+pub(crate) async fn websocket_session<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    func: WebSocketFunc,
+    max_size: usize,
+    shutdown: &WatchReceiver<bool>,
+    on_error: &ErrorHandler,
+) where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let (incoming, incoming_receiver): (Sender<Message>, Receiver<Message>) = channel(16);
+    let (outgoing, outgoing_receiver): (Sender<Message>, Receiver<Message>) = channel(16);
+
+    let websocket: WebSocket = WebSocket {
+        incoming: incoming_receiver,
+        outgoing,
+    };
+
+    let run = async move {
+        if let Err(e) = AssertUnwindSafe(func(websocket)).catch_unwind().await {
+            on_error(&ServerError::Panic(panic_message(&*e)));
+        }
+    };
+
+    let frames = frames(
+        reader,
+        writer,
+        incoming,
+        outgoing_receiver,
+        max_size,
+        shutdown.clone(),
+    );
+
+    let (_, frames_result) = join!(run, frames);
+
+    if let Err(e) = frames_result {
+        on_error(&ServerError::write(e));
+    }
+}
+/*
+ * Read Client Frames into Messages & write sent Messages as Frames
+ * Ends after the Close Handshake, on EOF or Shutdown
+ */
+async fn frames<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    incoming: Sender<Message>,
+    mut outgoing: Receiver<Message>,
+    max_size: usize,
+    mut shutdown: WatchReceiver<bool>,
+) -> Result<(), Error>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut chunk: [u8; 8192] = [0; 8192];
+    /*
+     * Frame Bytes read so far, Bytes the Client sent with the Upgrade
+     * Request are still in the Reader
+     */
+    let mut buffer: Vec<u8> = Vec::new();
+    /*
+     * Fragmented Message (Opcode, Data) until its final Frame
+     */
+    let mut fragments: Option<(u8, Vec<u8>)> = None;
+    let mut close_sent: bool = false;
+
+    loop {
+        /*
+         * Complete Frames in the Buffer
+         */
+        loop {
+            let frame: Frame = match parse_frame(&mut buffer, max_size) {
+                Ok(Some(x)) => x,
+                Ok(None) => break,
+                Err(code) => return close(writer, code, close_sent).await,
+            };
+
+            let message: Option<Message> = match frame.opcode {
+                /*
+                 * Continuation & Data Frames
+                 */
+                0x0..=0x2 => {
+                    let (opcode, mut data): (u8, Vec<u8>) = match (frame.opcode, fragments.take()) {
+                        (0x0, Some(x)) => x,
+                        (0x1 | 0x2, None) => (frame.opcode, Vec::new()),
+                        _ => return close(writer, 1002, close_sent).await,
+                    };
+
+                    data.extend_from_slice(&frame.payload);
+
+                    if data.len() > max_size {
+                        return close(writer, 1009, close_sent).await;
+                    }
+
+                    if !frame.fin {
+                        fragments = Some((opcode, data));
+                        None
+                    } else if opcode == 0x1 {
+                        match String::from_utf8(data) {
+                            Ok(x) => Some(Message::Text(x)),
+                            Err(_) => return close(writer, 1007, close_sent).await,
+                        }
+                    } else {
+                        Some(Message::Binary(data))
+                    }
+                }
+                /*
+                 * Close: echo it & end
+                 */
+                0x8 => {
+                    let reason: Option<(u16, String)> = match frame.payload.len() {
+                        0 => None,
+                        1 => return close(writer, 1002, close_sent).await,
+                        _ => Some((
+                            u16::from_be_bytes([frame.payload[0], frame.payload[1]]),
+                            String::from_utf8_lossy(&frame.payload[2..]).to_string(),
+                        )),
+                    };
+
+                    deliver(
+                        &incoming,
+                        Message::Close(reason.clone()),
+                        &mut outgoing,
+                        writer,
+                        &mut close_sent,
+                    )
+                    .await?;
+
+                    let code: u16 = reason.map(|(x, _)| x).unwrap_or(1000);
+                    return close(writer, code, close_sent).await;
+                }
+                /*
+                 * Ping is answered, Pong passed on
+                 */
+                0x9 => {
+                    writer.write_all(&encode_frame(0xA, &frame.payload)).await?;
+                    writer.flush().await?;
+                    None
+                }
+                0xA => Some(Message::Pong(frame.payload)),
+                _ => return close(writer, 1002, close_sent).await,
+            };
+            if let Some(x) = message {
+                deliver(&incoming, x, &mut outgoing, writer, &mut close_sent).await?;
+            }
+        }
+        /*
+         * Read more, write sent Messages or close on Shutdown
+         * After sending Close the Client has 5 seconds to answer it
+         */
+        select! {
+            x = read_timeout(close_sent.then(|| Duration::from_secs(5)), reader.read(&mut chunk)) => match x {
+                Some(Ok(0)) | Some(Err(_)) | None => return Ok(()),
+                Some(Ok(n)) => buffer.extend_from_slice(&chunk[..n]),
+            },
+            x = outgoing.recv(), if !close_sent => write_message(writer, x, &mut close_sent).await?,
+            _ = shutdown.changed(), if !close_sent => {
+                return close(writer, 1001, false).await;
+            },
+        }
+    }
+}
+/*
+ * Pass a Message to the Function
+ * Sent Messages are written meanwhile, a Function waiting to send can't block
+ * its own receiving
+ */
+async fn deliver<W: AsyncWrite + Unpin>(
+    incoming: &Sender<Message>,
+    message: Message,
+    outgoing: &mut Receiver<Message>,
+    writer: &mut W,
+    close_sent: &mut bool,
+) -> Result<(), Error> {
+    let send = incoming.send(message);
+
+    pin!(send);
+
+    loop {
+        select! {
+            /*
+             * Function stopped receiving (dropped the WebSocket)
+             */
+            _ = &mut send => return Ok(()),
+            x = outgoing.recv(), if !*close_sent => write_message(writer, x, close_sent).await?,
+        }
+    }
+}
+/*
+ * Write a sent Message as Frame, Close when the Function is done
+ */
+async fn write_message<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    message: Option<Message>,
+    close_sent: &mut bool,
+) -> Result<(), Error> {
+    let frame: Vec<u8> = match message {
+        Some(Message::Text(x)) => encode_frame(0x1, x.as_bytes()),
+        Some(Message::Binary(x)) => encode_frame(0x2, &x),
+        Some(Message::Ping(x)) => encode_frame(0x9, &x),
+        Some(Message::Pong(x)) => encode_frame(0xA, &x),
+        Some(Message::Close(x)) => {
+            *close_sent = true;
+            close_frame(
+                x.as_ref().map(|(x, _)| *x).unwrap_or(1000),
+                x.as_ref().map(|(_, x)| x.as_str()).unwrap_or(""),
+            )
+        }
+        None => {
+            *close_sent = true;
+            close_frame(1000, "")
+        }
+    };
+
+    writer.write_all(&frame).await?;
+    writer.flush().await
+}
+/*
+ * Send a Close Frame unless already sent
+ */
+async fn close<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    code: u16,
+    close_sent: bool,
+) -> Result<(), Error> {
+    if !close_sent {
+        writer.write_all(&close_frame(code, "")).await?;
+        writer.flush().await?;
+    }
+
+    Ok(())
+}
+
+fn close_frame(code: u16, reason: &str) -> Vec<u8> {
+    /*
+     * Control Frame Payload limit (125 Bytes) on a Character Boundary
+     */
+    let mut end: usize = reason.len().min(123);
+    while !reason.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    let mut payload: Vec<u8> = code.to_be_bytes().to_vec();
+    payload.extend_from_slice(&reason.as_bytes()[..end]);
+
+    encode_frame(0x8, &payload)
+}
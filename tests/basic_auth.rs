@@ -0,0 +1,58 @@
+mod common;
+
+use common::request;
+use oxidy::middlewares::basic_auth::basic_auth;
+use oxidy::{middleware, route, Context, Returns, Server};
+use std::collections::HashMap;
+
+async fn admin(mut c: Context) -> Returns {
+    let user: String = c.get_state("user").await.unwrap_or_default();
+    c.response.body = format!("Hello {}", user);
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut users: HashMap<String, String> = HashMap::new();
+    users.insert("admin".to_owned(), "secret".to_owned());
+
+    let mut app = Server::new();
+    app.add(middleware!(basic_auth(users)));
+    app.add(route!("get /", admin));
+    app
+}
+/*
+ * Missing, wrong & valid Credentials (admin:secret)
+ */
+#[tokio::test]
+async fn credentials() {
+    let app: Server = app();
+
+    for (authorization, status) in [
+        ("", 401),
+        ("Authorization: Basic YWRtaW46d3Jvbmc=\r\n", 401),
+        ("Authorization: Bearer YWRtaW46c2VjcmV0\r\n", 401),
+        ("Authorization: Basic YWRtaW46c2VjcmV0\r\n", 200),
+    ] {
+        let response: String = request(
+            &app,
+            format!("GET / HTTP/1.1\r\n{}\r\n", authorization).as_bytes(),
+        )
+        .await;
+
+        assert!(response.starts_with(&format!("HTTP/1.1 {}", status)));
+        assert_eq!(response.contains("WWW-Authenticate: Basic"), status == 401);
+    }
+}
+/*
+ * Authenticated User in the `user` State
+ */
+#[tokio::test]
+async fn user_state() {
+    let response: String = request(
+        &app(),
+        b"GET / HTTP/1.1\r\nAuthorization: Basic YWRtaW46c2VjcmV0\r\n\r\n",
+    )
+    .await;
+
+    assert!(response.ends_with("Hello admin"));
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use common::request;
+use oxidy::structs::body_reader::BodyReader;
+use oxidy::structs::multipart_error::MultipartError;
+use oxidy::{route, Context, Returns, Server};
+use tokio::io::AsyncReadExt;
+
+async fn chunks(mut c: Context) -> Returns {
+    let mut reader: BodyReader = c.request.body_reader().await;
+    let mut chunk: [u8; 4] = [0; 4];
+    let mut chunks: Vec<String> = Vec::new();
+
+    while let Ok(n) = reader.read(&mut chunk).await {
+        if n == 0 {
+            break;
+        }
+        chunks.push(String::from_utf8_lossy(&chunk[..n]).to_string());
+    }
+
+    c.response.body = chunks.join("|");
+    (c, None)
+}
+
+async fn twice(mut c: Context) -> Returns {
+    let mut body: Vec<u8> = Vec::new();
+    c.request
+        .body_reader()
+        .await
+        .read_to_end(&mut body)
+        .await
+        .unwrap();
+
+    let again: bool = c
+        .request
+        .body_reader()
+        .await
+        .read_to_end(&mut body)
+        .await
+        .is_err();
+    let multipart: bool = matches!(c.request.multipart().await, Err(MultipartError::Consumed));
+
+    c.response.body = format!("{} {} {}", body.len(), again, multipart);
+    (c, None)
+}
+
+fn app(stream_body: bool) -> Server {
+    let mut app = Server::new();
+    app.stream_body(stream_body);
+    app.add(route!("post /chunks", chunks));
+    app.add(route!("post /twice", twice));
+    app
+}
+/*
+ * Body read in Chunks of the Reader's Buffer
+ */
+#[tokio::test]
+async fn streamed_chunks() {
+    let response: String = request(
+        &app(true),
+        b"POST /chunks HTTP/1.1\r\nContent-Length: 10\r\n\r\nHello Body",
+    )
+    .await;
+
+    assert!(response.ends_with("Hell|o Bo|dy"));
+}
+/*
+ * The streamed Body is consumed by the first Reader
+ */
+#[tokio::test]
+async fn streamed_consumed() {
+    let response: String = request(
+        &app(true),
+        b"POST /twice HTTP/1.1\r\n\
+          Content-Type: multipart/form-data; boundary=XyZ\r\n\
+          Content-Length: 10\r\n\r\nHello Body",
+    )
+    .await;
+
+    assert!(response.ends_with("10 true true"));
+}
+/*
+ * The buffered Body stays available to further Readers
+ */
+#[tokio::test]
+async fn buffered() {
+    let response: String = request(
+        &app(false),
+        b"POST /twice HTTP/1.1\r\nContent-Length: 10\r\n\r\nHello Body",
+    )
+    .await;
+
+    assert!(response.ends_with("20 false false"));
+}
@@ -0,0 +1,25 @@
+use oxidy::Server;
+use std::net::SocketAddr;
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+/*
+ * Serve a raw Request on an in-memory Stream & read the whole Response
+ * The Stream holds Responses up to 1 MiB, they are read after serving
+ * The Client side is shut down after writing, so the Connection ends after
+ * the Requests
+ */
+#[allow(dead_code)]
+pub async fn request(app: &Server, raw: &[u8]) -> String {
+    let (mut client, stream) = duplex(1024 * 1024);
+
+    client.write_all(raw).await.unwrap();
+    client.shutdown().await.unwrap();
+
+    app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+        .await;
+
+    let mut response: Vec<u8> = Vec::new();
+    client.read_to_end(&mut response).await.unwrap();
+
+    String::from_utf8_lossy(&response).to_string()
+}
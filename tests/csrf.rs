@@ -0,0 +1,104 @@
+mod common;
+
+use common::request;
+use oxidy::middlewares::csrf::Csrf;
+use oxidy::middlewares::session::Sessions;
+use oxidy::{middleware, route, Context, Returns, Server};
+
+async fn token(mut c: Context) -> Returns {
+    c.response.body = c.csrf_token().await.unwrap_or_default();
+    (c, None)
+}
+
+async fn save(mut c: Context) -> Returns {
+    c.response.body = "Saved".to_owned();
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut app = Server::new();
+    app.add(middleware!(Sessions::new().middleware()));
+    app.add(middleware!(Csrf::new().middleware()));
+    app.add(route!("get /token", token));
+    app.add(route!("post /save", save));
+    app
+}
+/*
+ * Session Cookie & Token of a fresh Session
+ */
+async fn session(app: &Server) -> (String, String) {
+    let response: String = request(app, b"GET /token HTTP/1.1\r\n\r\n").await;
+
+    let session: String = response
+        .split("Set-Cookie: session=")
+        .nth(1)
+        .and_then(|x: &str| x.split(';').next())
+        .unwrap()
+        .to_owned();
+    let token: String = response.split("\r\n\r\n").nth(1).unwrap().to_owned();
+
+    (session, token)
+}
+/*
+ * Missing, wrong & valid Token in the Header
+ */
+#[tokio::test]
+async fn header_token() {
+    let app: Server = app();
+    let (session, token) = session(&app).await;
+
+    for (header, status) in [
+        (String::new(), "403"),
+        ("X-CSRF-Token: wrong\r\n".to_owned(), "403"),
+        (format!("X-CSRF-Token: {}\r\n", token), "200"),
+    ] {
+        let response: String = request(
+            &app,
+            format!(
+                "POST /save HTTP/1.1\r\nCookie: session={}\r\n{}\r\n",
+                session, header
+            )
+            .as_bytes(),
+        )
+        .await;
+
+        assert!(response.starts_with(&format!("HTTP/1.1 {}", status)));
+    }
+}
+/*
+ * Valid Token in the Form Field
+ */
+#[tokio::test]
+async fn form_token() {
+    let app: Server = app();
+    let (session, token) = session(&app).await;
+    let body: String = format!("_csrf={}", token);
+
+    let response: String = request(
+        &app,
+        format!(
+            "POST /save HTTP/1.1\r\nCookie: session={}\r\n\
+             Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{}",
+            session,
+            body.len(),
+            body
+        )
+        .as_bytes(),
+    )
+    .await;
+
+    assert!(response.ends_with("Saved"));
+}
+/*
+ * Without the Session Middleware every Request responds 500
+ */
+#[tokio::test]
+async fn without_sessions() {
+    let mut app = Server::new();
+    app.add(middleware!(Csrf::new().middleware()));
+    app.add(route!("get /token", token));
+
+    let response: String = request(&app, b"GET /token HTTP/1.1\r\n\r\n").await;
+
+    assert!(response.starts_with("HTTP/1.1 500"));
+}
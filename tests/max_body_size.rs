@@ -0,0 +1,114 @@
+mod common;
+
+use common::request;
+use oxidy::{route, Context, Returns, Server};
+use std::net::SocketAddr;
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+async fn upload(mut c: Context) -> Returns {
+    c.response.body = format!(
+        "Received {} bytes",
+        c.request.body().await.unwrap_or_default().len()
+    );
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut app = Server::new();
+    app.max_body_size(8);
+    app.add(route!("post /upload", upload));
+    app
+}
+/*
+ * Content Length over the Limit responds 413 & closes the Connection
+ */
+#[tokio::test]
+async fn content_length() {
+    let app: Server = app();
+
+    let response: String = request(
+        &app,
+        b"POST /upload HTTP/1.1\r\nContent-Length: 5\r\n\r\nhello",
+    )
+    .await;
+    assert!(response.ends_with("Received 5 bytes"));
+
+    let response: String = request(
+        &app,
+        b"POST /upload HTTP/1.1\r\nContent-Length: 9\r\n\r\nhello you",
+    )
+    .await;
+    assert!(response.starts_with("HTTP/1.1 413"));
+    assert!(response.contains("Connection: close"));
+}
+/*
+ * The Body is only sent after the interim Response
+ */
+#[tokio::test]
+async fn expect_continue() {
+    let app: Server = app();
+    let (mut client, stream) = duplex(1024);
+
+    let server = tokio::spawn(async move {
+        app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+            .await;
+    });
+
+    client
+        .write_all(b"POST /upload HTTP/1.1\r\nContent-Length: 5\r\nExpect: 100-continue\r\n\r\n")
+        .await
+        .unwrap();
+
+    let mut interim: [u8; 25] = [0; 25];
+    client.read_exact(&mut interim).await.unwrap();
+    assert_eq!(&interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+
+    client.write_all(b"hello").await.unwrap();
+    client.shutdown().await.unwrap();
+
+    let mut response: String = String::new();
+    client.read_to_string(&mut response).await.unwrap();
+    server.await.unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with("Received 5 bytes"));
+}
+/*
+ * Over the Limit the 413 is sent before the Body
+ */
+#[tokio::test]
+async fn expect_continue_too_large() {
+    let response: String = request(
+        &app(),
+        b"POST /upload HTTP/1.1\r\nContent-Length: 9\r\nExpect: 100-continue\r\n\r\n",
+    )
+    .await;
+
+    assert!(response.starts_with("HTTP/1.1 413"));
+    assert!(!response.contains("100 Continue"));
+}
+/*
+ * Chunked Bodies are counted while read
+ */
+#[tokio::test]
+async fn chunked() {
+    let app: Server = app();
+
+    for (body, status) in [
+        ("3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n", "200"),
+        ("5\r\nabcde\r\n5\r\nfghij\r\n0\r\n\r\n", "413"),
+        ("+5\r\nabcde\r\n0\r\n\r\n", "400"),
+    ] {
+        let response: String = request(
+            &app,
+            format!(
+                "POST /upload HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n{}",
+                body
+            )
+            .as_bytes(),
+        )
+        .await;
+
+        assert!(response.starts_with(&format!("HTTP/1.1 {}", status)));
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "metrics")]
+
+mod common;
+
+use common::request;
+use oxidy::middlewares::metrics::Metrics;
+use oxidy::{middleware, route, Context, Returns, Server};
+
+async fn user(c: Context) -> Returns {
+    (c, None)
+}
+/*
+ * Requests are counted by Route Pattern, unmatched ones share one label
+ */
+#[tokio::test]
+async fn requests_by_route_pattern() {
+    let mut app = Server::new();
+    let metrics: Metrics = Metrics::new().pool(app.pool());
+
+    app.add(middleware!(metrics.middleware()));
+    app.add(route!("get /user/:id", user));
+
+    for path in ["/user/1", "/user/2", "/missing"] {
+        request(&app, format!("GET {} HTTP/1.1\r\n\r\n", path).as_bytes()).await;
+    }
+
+    let text: String = metrics.render();
+
+    assert!(
+        text.contains("oxidy_requests_total{method=\"GET\",route=\"/user/:id\",status=\"200\"} 2")
+    );
+    assert!(
+        text.contains("oxidy_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1")
+    );
+    assert!(
+        text.contains("oxidy_request_duration_seconds_count{method=\"GET\",route=\"/user/:id\"} 2")
+    );
+    assert!(text.contains("oxidy_pool_queued 0"));
+}
+/*
+ * The Handler responds the Text Format
+ */
+#[tokio::test]
+async fn handler() {
+    let mut app = Server::new();
+    let metrics: Metrics = Metrics::new();
+
+    app.add(middleware!(metrics.middleware()));
+    app.add(route!("get /metrics", metrics.handler()));
+
+    let response: String = request(&app, b"GET /metrics HTTP/1.1\r\n\r\n").await;
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("text/plain; version=0.0.4; charset=utf-8"));
+    assert!(response.contains("# TYPE oxidy_requests_total counter"));
+}
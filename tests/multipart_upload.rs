@@ -0,0 +1,83 @@
+mod common;
+
+use common::request;
+use oxidy::structs::multipart_error::MultipartError;
+use oxidy::structs::temp_file::TempFile;
+use oxidy::structs::uploads::Uploads;
+use oxidy::{route, Context, Returns, Server};
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn temp_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("oxidy-uploads-{}", std::process::id()))
+}
+
+async fn upload(mut c: Context) -> Returns {
+    let uploads: Uploads = Uploads::new().temp_dir(temp_dir()).max_file_size(8);
+
+    match c.request.multipart_upload(&uploads).await {
+        Ok(m) => {
+            let title: String = m.part("title").unwrap().text();
+            let file: &TempFile = m.part("file").unwrap().temp_file().unwrap();
+            let content: String = std::fs::read_to_string(file.path()).unwrap();
+            c.response.body = format!("{} {} {}", title, file.size(), content);
+        }
+        Err(MultipartError::TooLarge(x)) => {
+            c.response.status = 413;
+            c.response.body = x;
+        }
+        Err(e) => {
+            c.response.status = 400;
+            c.response.body = e.to_string();
+        }
+    }
+    (c, None)
+}
+
+fn upload_request(content: &str) -> String {
+    let body: String = format!(
+        "--XyZ\r\n\
+         Content-Disposition: form-data; name=\"title\"\r\n\r\n\
+         Notes\r\n\
+         --XyZ\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n\r\n\
+         {}\r\n\
+         --XyZ--\r\n",
+        content
+    );
+
+    format!(
+        "POST /upload HTTP/1.1\r\n\
+         Content-Type: multipart/form-data; boundary=XyZ\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+/*
+ * File Parts on Disk, Files over the Limit & cleanup after the Request
+ */
+#[tokio::test]
+async fn temp_files() {
+    std::fs::create_dir_all(temp_dir()).unwrap();
+
+    let mut app = Server::new();
+    app.stream_body(true);
+    app.add(route!("post /upload", upload));
+
+    let response: String = request(&app, upload_request("Hello").as_bytes()).await;
+    assert!(response.ends_with("Notes 5 Hello"));
+
+    let response: String = request(&app, upload_request("Hello World").as_bytes()).await;
+    assert!(response.starts_with("HTTP/1.1 413"));
+
+    /* Temporary Files are deleted in the Background after the Request */
+    for _ in 0..100 {
+        if std::fs::read_dir(temp_dir()).unwrap().count() == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(std::fs::read_dir(temp_dir()).unwrap().count(), 0);
+    std::fs::remove_dir(temp_dir()).unwrap();
+}
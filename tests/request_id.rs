@@ -0,0 +1,76 @@
+mod common;
+
+use common::request;
+use oxidy::middlewares::request_id::RequestIds;
+use oxidy::{middleware, route, Context, Returns, Server};
+
+async fn route(mut c: Context) -> Returns {
+    c.response.body = c.request_id().await.unwrap_or_default();
+    (c, None)
+}
+
+fn app(ids: RequestIds) -> Server {
+    let mut app = Server::new();
+    app.add(middleware!(ids.middleware()));
+    app.add(route!("get /", route));
+    app
+}
+/*
+ * Incoming ID is kept & sent back
+ */
+#[tokio::test]
+async fn incoming() {
+    let response: String = request(
+        &app(RequestIds::new()),
+        b"GET / HTTP/1.1\r\nX-Request-Id: abc-123\r\n\r\n",
+    )
+    .await;
+
+    assert!(response.contains("X-Request-Id: abc-123\r\n"));
+    assert!(response.ends_with("\r\n\r\nabc-123"));
+}
+/*
+ * Generated UUID v4 without an incoming ID
+ */
+#[tokio::test]
+async fn generated() {
+    let response: String = request(&app(RequestIds::new()), b"GET / HTTP/1.1\r\n\r\n").await;
+    let id: &str = response.split("\r\n\r\n").nth(1).unwrap();
+
+    assert_eq!(id.len(), 36);
+    assert_eq!(id.chars().nth(14), Some('4'));
+    assert!(response.contains(&format!("X-Request-Id: {}\r\n", id)));
+}
+/*
+ * Invalid incoming IDs are replaced
+ */
+#[tokio::test]
+async fn invalid_incoming() {
+    let ids: RequestIds = RequestIds::new().generator(|| "generated".to_owned());
+    let long: String = "a".repeat(201);
+
+    for id in [long.as_str(), "a b"] {
+        let response: String = request(
+            &app(ids.clone()),
+            format!("GET / HTTP/1.1\r\nX-Request-Id: {}\r\n\r\n", id).as_bytes(),
+        )
+        .await;
+
+        assert!(response.ends_with("\r\n\r\ngenerated"));
+    }
+}
+/*
+ * Custom Header Name
+ */
+#[tokio::test]
+async fn header_name() {
+    let ids: RequestIds = RequestIds::new().header_name("X-Correlation-Id");
+    let response: String = request(
+        &app(ids),
+        b"GET / HTTP/1.1\r\nX-Correlation-Id: abc\r\n\r\n",
+    )
+    .await;
+
+    assert!(response.contains("X-Correlation-Id: abc\r\n"));
+    assert!(response.ends_with("\r\n\r\nabc"));
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::request;
+use oxidy::{route, Context, Returns, Server};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/*
+ * File of 10 Bytes, written once for all Tests
+ */
+fn path() -> PathBuf {
+    static PATH: OnceLock<PathBuf> = OnceLock::new();
+
+    PATH.get_or_init(|| {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("oxidy-send-file-{}.txt", std::process::id()));
+        std::fs::write(&path, "0123456789").unwrap();
+        path
+    })
+    .clone()
+}
+
+async fn file(mut c: Context) -> Returns {
+    if c.response.send_file(path()).await.is_err() {
+        c.response.status = 404;
+        c.response.body = "Not Found".to_owned();
+    }
+    (c, None)
+}
+
+async fn missing(mut c: Context) -> Returns {
+    if c.response
+        .send_file(path().with_extension("missing"))
+        .await
+        .is_err()
+    {
+        c.response.status = 404;
+        c.response.body = "Not Found".to_owned();
+    }
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut app = Server::new();
+    app.add(route!("get /", file));
+    app.add(route!("get /missing", missing));
+    app
+}
+
+async fn range(app: &Server, range: &str) -> String {
+    request(
+        app,
+        format!("GET / HTTP/1.1\r\nRange: {}\r\n\r\n", range).as_bytes(),
+    )
+    .await
+}
+/*
+ * Whole File with Content Type & Validators
+ */
+#[tokio::test]
+async fn whole_file() {
+    let response: String = request(&app(), b"GET / HTTP/1.1\r\n\r\n").await;
+
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("Content-Type: text/plain"));
+    assert!(response.contains("Content-Length: 10\r\n"));
+    assert!(response.contains("ETag: "));
+    assert!(response.contains("Last-Modified: "));
+    assert!(response.ends_with("\r\n\r\n0123456789"));
+}
+/*
+ * A current cached Copy responds 304
+ */
+#[tokio::test]
+async fn not_modified() {
+    let app: Server = app();
+
+    let response: String = request(&app, b"GET / HTTP/1.1\r\n\r\n").await;
+    let etag: &str = response
+        .split("ETag: ")
+        .nth(1)
+        .and_then(|x: &str| x.split("\r\n").next())
+        .unwrap();
+
+    let response: String = request(
+        &app,
+        format!("GET / HTTP/1.1\r\nIf-None-Match: {}\r\n\r\n", etag).as_bytes(),
+    )
+    .await;
+
+    assert!(response.starts_with("HTTP/1.1 304"));
+    assert!(response.ends_with("\r\n\r\n"));
+}
+/*
+ * Missing File errors, the Route responds 404
+ */
+#[tokio::test]
+async fn not_found() {
+    let response: String = request(&app(), b"GET /missing HTTP/1.1\r\n\r\n").await;
+
+    assert!(response.starts_with("HTTP/1.1 404"));
+}
+/*
+ * Single, suffix, multiple, merged & unsatisfiable Ranges
+ */
+#[tokio::test]
+async fn ranges() {
+    let app: Server = app();
+
+    let response: String = range(&app, "bytes=2-5").await;
+    assert!(response.starts_with("HTTP/1.1 206"));
+    assert!(response.contains("Content-Range: bytes 2-5/10"));
+    assert!(response.ends_with("\r\n\r\n2345"));
+
+    let response: String = range(&app, "bytes=-3").await;
+    assert!(response.ends_with("789"));
+
+    let response: String = range(&app, "bytes=0-0, 9-").await;
+    assert!(response.contains("Content-Type: multipart/byteranges; boundary="));
+    assert!(response.contains("Content-Range: bytes 0-0/10\r\n\r\n0\r\n"));
+    assert!(response.contains("Content-Range: bytes 9-9/10\r\n\r\n9\r\n"));
+
+    let response: String = range(&app, "bytes=0-5, 2-7, 8-8, 0-").await;
+    assert!(response.contains("Content-Range: bytes 0-9/10"));
+    assert!(response.ends_with("\r\n\r\n0123456789"));
+
+    let response: String = range(&app, "bytes=20-").await;
+    assert!(response.starts_with("HTTP/1.1 416"));
+    assert!(response.contains("Content-Range: bytes */10"));
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::request;
+use oxidy::{route, Context, Returns, Server};
+use std::net::SocketAddr;
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+async fn panic(_c: Context) -> Returns {
+    panic!("Route Panic");
+}
+
+async fn index(mut c: Context) -> Returns {
+    c.response.body = "OK".to_owned();
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut app = Server::new();
+    app.add(route!("get /panic", panic));
+    app.add(route!("get /", index));
+    app
+}
+/*
+ * A panicking Function responds 500 & further Requests are still served
+ */
+#[tokio::test]
+async fn panic_responds_500() {
+    let app: Server = app();
+
+    for (path, status) in [("/panic", "500"), ("/", "200")] {
+        let response: String =
+            request(&app, format!("GET {} HTTP/1.1\r\n\r\n", path).as_bytes()).await;
+
+        assert!(response.starts_with(&format!("HTTP/1.1 {}", status)));
+    }
+}
+/*
+ * A malformed Request (Request Line, Header Lines or `Content-Length`)
+ * responds 400 & closes the Connection
+ */
+#[tokio::test]
+async fn malformed_request() {
+    let app: Server = app();
+
+    for raw in [
+        "garbage\r\n\r\n",
+        "GET /\r\n\r\n",
+        "GET / HTTP/1.1 extra\r\n\r\n",
+        "G(E)T / HTTP/1.1\r\n\r\n",
+        "GET no-slash HTTP/1.1\r\n\r\n",
+        "GET / HTTX/1.1\r\n\r\n",
+        "GET / HTTP/1.1\r\nno colon\r\n\r\n",
+        "GET / HTTP/1.1\r\nBad Name: x\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost: a\r\n folded\r\n\r\n",
+        "GET / HTTP/1.1\r\nContent-Length: abc\r\n\r\n",
+        "\x00\x01\x02\r\n\r\n",
+    ] {
+        let (mut client, stream) = duplex(1024);
+
+        /* Connection stays open on the Client side, the Server closes it */
+        client.write_all(raw.as_bytes()).await.unwrap();
+
+        app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+            .await;
+
+        let mut response: String = String::new();
+        client.read_to_string(&mut response).await.unwrap();
+
+        assert!(
+            response.starts_with("HTTP/1.1 400 Bad Request"),
+            "{:?}",
+            raw
+        );
+        assert!(response.contains("Connection: close"));
+    }
+}
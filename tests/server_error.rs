@@ -0,0 +1,52 @@
+mod common;
+
+use common::request;
+use oxidy::structs::server_error::ServerError;
+use oxidy::{route, Context, Returns, Server};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+async fn large(mut c: Context) -> Returns {
+    c.response.body = "x".repeat(64 * 1024);
+    (c, None)
+}
+/*
+ * Client closes before reading the whole Response, the Server keeps serving
+ */
+#[tokio::test]
+async fn client_disconnect() {
+    let errors: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let errors_handler: Arc<Mutex<Vec<String>>> = errors.clone();
+
+    let mut app = Server::new();
+    app.add(route!("get /", large));
+    app.on_error(move |e: &ServerError| {
+        errors_handler.lock().unwrap().push(match e {
+            ServerError::Disconnect(_) => "Disconnect".to_owned(),
+            e => e.to_string(),
+        });
+    });
+
+    let (mut client, stream) = duplex(1024);
+
+    client.write_all(b"GET / HTTP/1.1\r\n\r\n").await.unwrap();
+
+    let server = tokio::spawn({
+        let app: Server = app.clone();
+        async move {
+            app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+                .await
+        }
+    });
+
+    let mut start: [u8; 512] = [0; 512];
+    client.read_exact(&mut start).await.unwrap();
+    drop(client);
+
+    server.await.unwrap();
+    assert_eq!(*errors.lock().unwrap(), vec!["Disconnect".to_owned()]);
+
+    let response: String = request(&app, b"GET / HTTP/1.1\r\n\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use common::request;
+use oxidy::middlewares::session::Sessions;
+use oxidy::{middleware, route, Context, Returns, Server};
+
+async fn login(c: Context) -> Returns {
+    if let Some(session) = c.session().await {
+        session.set("user", "john").await;
+    }
+    (c, None)
+}
+
+async fn me(mut c: Context) -> Returns {
+    if let Some(session) = c.session().await {
+        c.response.body = session.get("user").await.unwrap_or_default();
+    }
+    (c, None)
+}
+
+async fn logout(c: Context) -> Returns {
+    if let Some(session) = c.session().await {
+        session.destroy().await;
+    }
+    (c, None)
+}
+
+fn app() -> Server {
+    let mut app = Server::new();
+    app.add(middleware!(Sessions::new().middleware()));
+    app.add(route!("post /login", login));
+    app.add(route!("get /me", me));
+    app.add(route!("post /logout", logout));
+    app
+}
+
+fn session_id(response: &str) -> String {
+    response
+        .split("Set-Cookie: session=")
+        .nth(1)
+        .and_then(|x: &str| x.split(';').next())
+        .unwrap()
+        .to_owned()
+}
+/*
+ * Session across Requests, unchanged Sessions get no Cookie
+ */
+#[tokio::test]
+async fn across_requests() {
+    let app: Server = app();
+
+    let response: String = request(&app, b"POST /login HTTP/1.1\r\n\r\n").await;
+    let id: String = session_id(&response);
+
+    assert_eq!(id.len(), 64);
+
+    let response: String = request(
+        &app,
+        format!("GET /me HTTP/1.1\r\nCookie: session={}\r\n\r\n", id).as_bytes(),
+    )
+    .await;
+
+    assert!(response.ends_with("john"));
+    assert!(!response.contains("Set-Cookie"));
+}
+/*
+ * New Sessions without Values get no Cookie
+ */
+#[tokio::test]
+async fn empty_session() {
+    let response: String = request(&app(), b"GET /me HTTP/1.1\r\n\r\n").await;
+
+    assert!(!response.contains("Set-Cookie"));
+}
+/*
+ * Unknown IDs start a new Session
+ */
+#[tokio::test]
+async fn unknown_id() {
+    let response: String = request(
+        &app(),
+        b"GET /me HTTP/1.1\r\nCookie: session=unknown\r\n\r\n",
+    )
+    .await;
+
+    assert!(response.ends_with("\r\n\r\n"));
+}
+/*
+ * Destroyed Sessions are deleted with an expired Cookie
+ */
+#[tokio::test]
+async fn destroy() {
+    let app: Server = app();
+
+    let response: String = request(&app, b"POST /login HTTP/1.1\r\n\r\n").await;
+    let id: String = session_id(&response);
+
+    let response: String = request(
+        &app,
+        format!("POST /logout HTTP/1.1\r\nCookie: session={}\r\n\r\n", id).as_bytes(),
+    )
+    .await;
+
+    assert!(response.contains("Set-Cookie: session=;"));
+    assert!(response.contains("Max-Age=0"));
+
+    let response: String = request(
+        &app,
+        format!("GET /me HTTP/1.1\r\nCookie: session={}\r\n\r\n", id).as_bytes(),
+    )
+    .await;
+
+    assert!(!response.ends_with("john"));
+}
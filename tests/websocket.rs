@@ -0,0 +1,137 @@
+#![cfg(feature = "websocket")]
+
+use oxidy::structs::websocket::{Message, WebSocket};
+use oxidy::{route, Context, Returns, Server};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{duplex, AsyncReadExt, AsyncWriteExt};
+
+const HANDSHAKE: &[u8] = b"GET /ws HTTP/1.1\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+    Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+
+async fn upper(mut c: Context) -> Returns {
+    let upgraded: bool = c
+        .websocket(|mut ws: WebSocket| async move {
+            while let Some(Message::Text(x)) = ws.recv().await {
+                ws.send(Message::Text(x.to_uppercase())).await.ok();
+            }
+        })
+        .await;
+
+    if !upgraded {
+        c.response.status = 400;
+    }
+    (c, None)
+}
+
+async fn echo(mut c: Context) -> Returns {
+    c.websocket(|mut ws: WebSocket| async move {
+        while let Some(Message::Text(x)) = ws.recv().await {
+            ws.send(Message::Text(x)).await.ok();
+        }
+    })
+    .await;
+    (c, None)
+}
+/*
+ * Handshake, a masked Text Frame & the echoed Close Frame
+ */
+#[tokio::test]
+async fn handshake_message_close() {
+    let mut app = Server::new();
+    app.add(route!("get /ws", upper));
+
+    let (mut client, stream) = duplex(4096);
+
+    let server = tokio::spawn(async move {
+        app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+            .await;
+    });
+
+    client.write_all(HANDSHAKE).await.unwrap();
+
+    /* Masked Text Frame "hi" */
+    let mask: [u8; 4] = [1, 2, 3, 4];
+    let mut frame: Vec<u8> = vec![0x81, 0x80 | 2];
+    frame.extend_from_slice(&mask);
+    frame.extend(b"hi".iter().enumerate().map(|(i, x)| x ^ mask[i % 4]));
+    client.write_all(&frame).await.unwrap();
+
+    let mut response: Vec<u8> = Vec::new();
+    while !response.ends_with(&[0x81, 2, b'H', b'I']) {
+        let mut chunk: [u8; 1024] = [0; 1024];
+        let n: usize = client.read(&mut chunk).await.unwrap();
+        response.extend_from_slice(&chunk[..n]);
+    }
+
+    let head: String = String::from_utf8_lossy(&response).to_string();
+    assert!(head.starts_with("HTTP/1.1 101 Switching Protocols"));
+    assert!(head.contains("Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+
+    /* Masked Close Frame, echoed */
+    client.write_all(&[0x88, 0x80, 0, 0, 0, 0]).await.unwrap();
+
+    let mut close: Vec<u8> = Vec::new();
+    client.read_to_end(&mut close).await.unwrap();
+    assert_eq!(close, vec![0x88, 2, 0x03, 0xe8]);
+
+    server.await.unwrap();
+}
+/*
+ * No valid Handshake, the Route responds itself
+ */
+#[tokio::test]
+async fn invalid_handshake() {
+    let mut app = Server::new();
+    app.add(route!("get /ws", upper));
+
+    let (mut client, stream) = duplex(4096);
+
+    client
+        .write_all(b"GET /ws HTTP/1.1\r\nConnection: close\r\n\r\n")
+        .await
+        .unwrap();
+
+    app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+        .await;
+
+    let mut response: String = String::new();
+    client.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request"));
+}
+/*
+ * Pipelined Messages are read while the Function's Messages are written
+ */
+#[tokio::test]
+async fn pipelined_messages() {
+    let mut app = Server::new();
+    app.add(route!("get /ws", echo));
+
+    let (mut client, stream) = duplex(64 * 1024);
+
+    tokio::spawn(async move {
+        app.serve(stream, SocketAddr::from(([127, 0, 0, 1], 8080)))
+            .await;
+    });
+
+    let mut request: Vec<u8> = HANDSHAKE.to_vec();
+
+    /* 100 masked Text Frames "hi" at once */
+    for _ in 0..100 {
+        request.extend_from_slice(&[0x81, 0x80 | 2, 0, 0, 0, 0, b'h', b'i']);
+    }
+    client.write_all(&request).await.unwrap();
+
+    let echo: [u8; 4] = [0x81, 2, b'h', b'i'];
+    let mut response: Vec<u8> = Vec::new();
+
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while response.windows(4).filter(|x| *x == echo).count() < 100 {
+            let mut chunk: [u8; 1024] = [0; 1024];
+            let n: usize = client.read(&mut chunk).await.unwrap();
+            response.extend_from_slice(&chunk[..n]);
+        }
+    })
+    .await
+    .unwrap();
+}